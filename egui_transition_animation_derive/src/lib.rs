@@ -0,0 +1,220 @@
+//! Derive macros for [`egui_transition_animation`](https://docs.rs/egui_transition_animation).
+//!
+//! You shouldn't depend on this crate directly, use the `derive` feature of `egui_transition_animation` instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Fields, LitInt};
+
+/// Implements `PageOrder` for an enum.
+///
+/// Pages are ordered by their declaration order. This can be overridden
+/// with `#[page_order(index = N)]` on a variant. Variants without the attribute
+/// get the index of the previous variant plus one, just like enum discriminants.
+///
+/// Fields of data-carrying variants are filled with [`Default::default`] in `PageOrder::all`.
+#[proc_macro_derive(PageOrder, attributes(page_order))]
+pub fn derive_page_order(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match page_order_impl(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn page_order_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`PageOrder` can only be derived for enums",
+        ));
+    };
+
+    let pages = page_indices(&data.variants)?;
+
+    let index_arms = pages.iter().map(|(index, variant)| {
+        let ident = &variant.ident;
+        match variant.fields {
+            Fields::Unit => quote!(Self::#ident => #index),
+            Fields::Unnamed(_) => quote!(Self::#ident(..) => #index),
+            Fields::Named(_) => quote!(Self::#ident { .. } => #index),
+        }
+    });
+
+    let mut sorted = pages.clone();
+    sorted.sort_by_key(|(index, _)| *index);
+    let all_pages = sorted.iter().map(|(_, variant)| {
+        let ident = &variant.ident;
+        match &variant.fields {
+            Fields::Unit => quote!(Self::#ident),
+            Fields::Unnamed(fields) => {
+                let defaults = fields
+                    .unnamed
+                    .iter()
+                    .map(|_| quote!(::core::default::Default::default()));
+                quote!(Self::#ident(#(#defaults),*))
+            }
+            Fields::Named(fields) => {
+                let defaults = fields.named.iter().map(|field| {
+                    let name = &field.ident;
+                    quote!(#name: ::core::default::Default::default())
+                });
+                quote!(Self::#ident { #(#defaults),* })
+            }
+        }
+    });
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Empty enums have no values, so `index` can never be called.
+    let index_body = if pages.is_empty() {
+        quote!(match *self {})
+    } else {
+        quote!(match self { #(#index_arms,)* })
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::egui_transition_animation::PageOrder for #name #ty_generics #where_clause {
+            fn index(&self) -> usize {
+                #index_body
+            }
+
+            fn all() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(#all_pages),*]
+            }
+        }
+    })
+}
+
+/// Assigns indices to the variants, failing if any index is used twice
+fn page_indices<'v>(
+    variants: impl IntoIterator<Item = &'v syn::Variant>,
+) -> syn::Result<Vec<(usize, &'v syn::Variant)>> {
+    let mut pages: Vec<(usize, &syn::Variant)> = Vec::new();
+    let mut next_index = 0usize;
+    for variant in variants {
+        let index = match explicit_index(variant)? {
+            Some(index) => index,
+            None => next_index,
+        };
+        if let Some((_, other)) = pages.iter().find(|(other_index, _)| *other_index == index) {
+            return Err(syn::Error::new(
+                variant.span(),
+                format!("page index {index} is already used by `{}`", other.ident),
+            ));
+        }
+        pages.push((index, variant));
+        next_index = index + 1;
+    }
+    Ok(pages)
+}
+
+/// Parses `#[page_order(index = N)]`
+fn explicit_index(variant: &syn::Variant) -> syn::Result<Option<usize>> {
+    let mut index = None;
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("page_order"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("index") {
+                let lit: LitInt = meta.value()?.parse()?;
+                index = Some(lit.base10_parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown `page_order` attribute, expected `index`"))
+            }
+        })?;
+    }
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::{parse_quote, ItemEnum};
+
+    fn indices(item: &ItemEnum) -> syn::Result<Vec<(usize, String)>> {
+        Ok(page_indices(&item.variants)?
+            .into_iter()
+            .map(|(index, variant)| (index, variant.ident.to_string()))
+            .collect())
+    }
+
+    #[test]
+    fn indices_follow_declaration_order() {
+        let item: ItemEnum = parse_quote! {
+            enum Page { Home, Settings(u32), About { version: String } }
+        };
+        assert_eq!(
+            indices(&item).unwrap(),
+            [
+                (0, "Home".into()),
+                (1, "Settings".into()),
+                (2, "About".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn explicit_indices_continue_like_discriminants() {
+        let item: ItemEnum = parse_quote! {
+            enum Page {
+                #[page_order(index = 5)]
+                Home,
+                Settings,
+                #[page_order(index = 1)]
+                About,
+                Help,
+            }
+        };
+        assert_eq!(
+            indices(&item).unwrap(),
+            [
+                (5, "Home".into()),
+                (6, "Settings".into()),
+                (1, "About".into()),
+                (2, "Help".into())
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_index_is_an_error() {
+        let item: ItemEnum = parse_quote! {
+            enum Page {
+                Home,
+                Settings,
+                #[page_order(index = 1)]
+                About,
+            }
+        };
+        let err = indices(&item).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "page index 1 is already used by `Settings`"
+        );
+    }
+
+    #[test]
+    fn unknown_attribute_is_an_error() {
+        let item: ItemEnum = parse_quote! {
+            enum Page {
+                #[page_order(position = 1)]
+                Home,
+            }
+        };
+        assert!(indices(&item).is_err());
+    }
+
+    #[test]
+    fn only_enums_are_supported() {
+        let input: DeriveInput = parse_quote! {
+            struct Page;
+        };
+        assert!(page_order_impl(&input).is_err());
+    }
+}
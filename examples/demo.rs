@@ -19,7 +19,7 @@ fn main() -> eframe::Result {
     )
 }
 
-#[derive(Debug, Eq, PartialEq, PartialOrd, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash, PageOrder)]
 enum Page {
     About,
     Configure,
@@ -92,7 +92,7 @@ impl eframe::App for MyApp {
                         },
                     );
                     ui.vertical(|ui| {
-                        let state_s = animated_pager(
+                        let state_s = animated_pager_ordered(
                             ui,
                             self.page.clone(),
                             &TransitionStyle {
@@ -2,7 +2,7 @@ fn main() -> eframe::Result {
     use eframe::egui;
     use egui_transition_animation::prelude::*;
 
-    #[derive(PartialEq, PartialOrd, Clone, Eq)]
+    #[derive(PartialEq, PartialOrd, Clone, Eq, Hash)]
     enum Page {
         Page1,
         Page2,
@@ -0,0 +1,250 @@
+use egui::{Context, Id, Ui};
+use std::hash::Hash;
+
+use crate::{animated_pager_with_direction, PageIndicator, PagerRet, TransitionStyle};
+
+/// Persisted state of an [`AnimatedCarousel`].
+///
+/// Use it to navigate the carousel from outside of it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CarouselState {
+    /// Index of the target item
+    pub index: usize,
+    /// Whether the last navigation went forward
+    forward: bool,
+    /// Seconds left until the carousel advances automatically
+    remaining: f32,
+    /// [Time](egui::InputState::time) of the last frame the carousel was shown
+    last_time: f64,
+    /// [Pass](Context::cumulative_pass_nr) in which the carousel was shown last time
+    last_pass: Option<u64>,
+}
+
+impl CarouselState {
+    fn new() -> Self {
+        Self {
+            index: 0,
+            forward: true,
+            remaining: f32::INFINITY,
+            last_time: 0.0,
+            last_pass: None,
+        }
+    }
+
+    /// Loads the state of the carousel with given `id` or returns the default one.
+    pub fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|data| *data.get_persisted_mut_or_insert_with(id, Self::new))
+    }
+
+    /// Stores the state, so it's used by the carousel with given `id` in the next frame.
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.insert_persisted(id, self));
+    }
+
+    /// Advance to the next item, wrapping around to the first one after the last one.
+    ///
+    /// Wrapping is always animated as going _forward_.
+    pub fn next(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        self.index = (self.index + 1) % len;
+        self.forward = true;
+    }
+
+    /// Go back to the previous item, wrapping around to the last one before the first one.
+    ///
+    /// Wrapping is always animated as going _backward_.
+    pub fn prev(&mut self, len: usize) {
+        if len == 0 {
+            return;
+        }
+        self.index = (self.index + len - 1) % len;
+        self.forward = false;
+    }
+
+    /// Jump to the item at `index`. The direction is determined by comparing it with the current index.
+    pub fn go_to(&mut self, index: usize) {
+        self.forward = index >= self.index;
+        self.index = index;
+    }
+}
+
+/// Cycles through a slice of items with transition animation between them.
+///
+/// Unlike [`animated_pager`](crate::animated_pager), moving from the last item to the first one
+/// is animated as going _forward_ and vice versa.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::AnimatedCarousel;
+///
+/// let slides = ["First", "Second", "Third"];
+/// AnimatedCarousel::new("slides")
+///     .auto_advance(5.0)
+///     .show(ui, &slides, |ui, slide| ui.heading(*slide));
+/// # }
+/// ```
+pub struct AnimatedCarousel {
+    id: Id,
+    style: Option<TransitionStyle>,
+    auto_advance: Option<f32>,
+    buttons: bool,
+    indicator: bool,
+    looping: bool,
+}
+
+impl AnimatedCarousel {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            style: None,
+            auto_advance: None,
+            buttons: true,
+            indicator: false,
+            looping: true,
+        }
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::horizontal`].
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Automatically advance to the next item every `interval` seconds.
+    ///
+    /// The countdown pauses while the carousel is hovered or isn't shown.
+    pub fn auto_advance(mut self, interval: f32) -> Self {
+        self.auto_advance = Some(interval);
+        self
+    }
+
+    /// Whether to show the next/previous buttons. Defaults to `true`.
+    pub fn buttons(mut self, buttons: bool) -> Self {
+        self.buttons = buttons;
+        self
+    }
+
+    /// Whether to show a [`PageIndicator`] between the next/previous buttons. Defaults to `false`.
+    pub fn indicator(mut self, indicator: bool) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Whether the last item is followed by the first one. Defaults to `true`.
+    ///
+    /// When disabled, the buttons are disabled at the ends and auto-advance stops at the last item.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Id of the persisted [`CarouselState`].
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    /// Shows the carousel. Returns [`None`] if `items` is empty.
+    ///
+    /// `add_contents` may be called with a different item than the target one if an animation is running.
+    pub fn show<T, Ret>(
+        self,
+        ui: &mut Ui,
+        items: &[T],
+        add_contents: impl FnOnce(&mut Ui, &T) -> Ret,
+    ) -> Option<PagerRet<usize, Ret>> {
+        if items.is_empty() {
+            return None;
+        }
+        let len = items.len();
+
+        let mut state = CarouselState::load(ui.ctx(), self.id);
+        state.index = state.index.min(len - 1);
+
+        let style = self
+            .style
+            .unwrap_or_else(|| TransitionStyle::horizontal(ui));
+        let forward = state.forward;
+
+        let mut hovered = false;
+        let pager_ret = ui
+            .vertical(|ui| {
+                let pager_ret = animated_pager_with_direction(
+                    ui,
+                    state.index,
+                    &style,
+                    self.id.with("pager"),
+                    |_, _| forward,
+                    // The previous page may be out of bounds if `items` has shrunk
+                    |ui, index| add_contents(ui, &items[index.min(len - 1)]),
+                );
+                if self.buttons || self.indicator {
+                    ui.horizontal(|ui| {
+                        let at_start = !self.looping && state.index == 0;
+                        let at_end = !self.looping && state.index == len - 1;
+                        if self.buttons
+                            && ui.add_enabled(!at_start, egui::Button::new("⏴")).clicked()
+                        {
+                            state.prev(len);
+                            state.remaining = f32::INFINITY;
+                        }
+                        if self.indicator {
+                            let mut index = state.index;
+                            if PageIndicator::new(self.id.with("pager"))
+                                .show_index(ui, len, &mut index)
+                                .changed()
+                            {
+                                state.go_to(index);
+                                state.remaining = f32::INFINITY;
+                            }
+                        }
+                        if self.buttons && ui.add_enabled(!at_end, egui::Button::new("⏵")).clicked()
+                        {
+                            state.next(len);
+                            state.remaining = f32::INFINITY;
+                        }
+                    });
+                }
+                hovered = ui.rect_contains_pointer(ui.min_rect());
+                pager_ret
+            })
+            .inner;
+
+        let now = ui.input(|input| input.time);
+        let pass = ui.ctx().cumulative_pass_nr();
+        // Time when the carousel wasn't shown (eg. on another page or in a collapsed section) doesn't count
+        let elapsed = if state
+            .last_pass
+            .is_some_and(|last_pass| last_pass + 1 == pass)
+        {
+            (now - state.last_time).max(0.) as f32
+        } else {
+            0.
+        };
+        match self.auto_advance {
+            Some(interval) if self.looping || state.index + 1 < len => {
+                // Restart the countdown after a manual navigation or when auto-advance is enabled
+                if !state.remaining.is_finite() {
+                    state.remaining = interval;
+                } else if !hovered {
+                    state.remaining -= elapsed;
+                }
+                if state.remaining <= 0.0 {
+                    state.next(len);
+                    state.remaining = interval;
+                }
+                if !hovered {
+                    ui.ctx()
+                        .request_repaint_after_secs(state.remaining.max(0.0));
+                }
+            }
+            _ => state.remaining = f32::INFINITY,
+        }
+        state.last_time = now;
+        state.last_pass = Some(pass);
+
+        state.store(ui.ctx(), self.id);
+        Some(pager_ret)
+    }
+}
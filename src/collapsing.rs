@@ -0,0 +1,197 @@
+use egui::{
+    emath::{remap, Rot2},
+    Id, InnerResponse, Rect, Response, Sense, Shape, Stroke, TextStyle, TextWrapMode, Ui, Vec2,
+    WidgetText,
+};
+use std::{f32::consts::TAU, hash::Hash};
+
+use crate::{reveal::Reveal, TransitionStyle};
+
+/// Response of [`AnimatedCollapsing::show`].
+pub struct CollapsingResponse<R> {
+    /// Response of the clickable header
+    pub header_response: Response,
+    /// Response of the body, [`None`] if it's fully collapsed
+    pub body: Option<InnerResponse<R>>,
+    /// How much the section is open, between `0.0` (collapsed) and `1.0` (open), with easing applied
+    pub openness: f32,
+}
+
+/// Collapsing section whose body slides in from above, fades and eases its height when opened or closed.
+///
+/// Unlike [`egui::CollapsingHeader`], which only clips the body, this moves the body using a [`TransitionStyle`],
+/// so it matches the motion of the pagers. The arrow in the header rotates in sync with the body.
+/// The open state is stored under an id derived from the parent [`Ui`], so it works inside pagers
+/// and other collapsing sections.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::AnimatedCollapsing;
+///
+/// AnimatedCollapsing::new("Details")
+///     .default_open(true)
+///     .show(ui, |ui| {
+///         ui.label("Lots of details");
+///     });
+/// # }
+/// ```
+pub struct AnimatedCollapsing {
+    heading: WidgetText,
+    id_salt: Option<Id>,
+    style: Option<TransitionStyle>,
+    default_open: bool,
+}
+
+impl AnimatedCollapsing {
+    pub fn new(heading: impl Into<WidgetText>) -> Self {
+        Self {
+            heading: heading.into(),
+            id_salt: None,
+            style: None,
+            default_open: false,
+        }
+    }
+
+    /// Salt of the id storing the open state. Defaults to the text of the heading.
+    pub fn id_salt(mut self, id_salt: impl Hash) -> Self {
+        self.id_salt = Some(Id::new(id_salt));
+        self
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::vertical`].
+    ///
+    /// The body always slides vertically, [`TransitionStyle::t_type`] is ignored.
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Whether the section is open when shown for the first time. Defaults to `false`.
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Shows the header and, unless the section is collapsed, the body.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let id =
+            ui.make_persistent_id(self.id_salt.unwrap_or_else(|| Id::new(self.heading.text())));
+        let style = self.style.unwrap_or_else(|| TransitionStyle::vertical(ui));
+
+        let mut open = ui
+            .ctx()
+            .data_mut(|data| data.get_persisted::<bool>(id))
+            .unwrap_or(self.default_open);
+
+        let header_response = header(ui, self.heading, &style, id, &mut open);
+        ui.ctx().data_mut(|data| data.insert_persisted(id, open));
+
+        let openness = ui.ctx().animate_bool_with_time_and_easing(
+            id.with("openness"),
+            open,
+            style.duration,
+            style.easing,
+        );
+        paint_arrow(ui, &header_response, &style, openness);
+
+        let body = (openness > 0.).then(|| {
+            let reveal = Reveal {
+                // Slide in from above
+                slide: Vec2::new(0., -style.amount),
+                fade: true,
+                collapse: true,
+            };
+            reveal.show(ui, id.with("body"), openness, |ui| {
+                ui.indent(id, add_body).inner
+            })
+        });
+
+        CollapsingResponse {
+            header_response,
+            body,
+            openness,
+        }
+    }
+}
+
+/// Allocates the clickable header and toggles `open` when it's clicked
+fn header(
+    ui: &mut Ui,
+    heading: WidgetText,
+    style: &TransitionStyle,
+    id: Id,
+    open: &mut bool,
+) -> Response {
+    let icon_width = ui.spacing().icon_width;
+    let icon_spacing = ui.spacing().icon_spacing;
+    let galley = heading.into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    );
+    let size = Vec2::new(
+        icon_width + icon_spacing + galley.size().x,
+        galley.size().y.max(icon_width),
+    ) + ui.spacing().button_padding * 2.;
+
+    let (rect, mut response) = ui.allocate_exact_size(size, Sense::click());
+    if response.clicked() {
+        *open = !*open;
+        response.mark_changed();
+    }
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        if response.hovered() {
+            ui.painter()
+                .rect_filled(rect, visuals.rounding, visuals.weak_bg_fill);
+        }
+        let inner = rect.shrink2(ui.spacing().button_padding);
+        let text_pos = if style.text_direction.is_rtl() {
+            inner.right_center() - Vec2::new(icon_width + icon_spacing + galley.size().x, 0.)
+        } else {
+            inner.left_center() + Vec2::new(icon_width + icon_spacing, 0.)
+        };
+        ui.painter().galley(
+            text_pos - Vec2::new(0., galley.size().y / 2.),
+            galley,
+            visuals.text_color(),
+        );
+    }
+    ui.ctx().check_for_id_clash(id, rect, "AnimatedCollapsing");
+    response
+}
+
+/// Paints the arrow pointing towards the heading text when collapsed and down when open
+fn paint_arrow(ui: &Ui, response: &Response, style: &TransitionStyle, openness: f32) {
+    let icon_width = ui.spacing().icon_width;
+    let inner = response.rect.shrink2(ui.spacing().button_padding);
+    let center = if style.text_direction.is_rtl() {
+        inner.right_center() - Vec2::new(icon_width / 2., 0.)
+    } else {
+        inner.left_center() + Vec2::new(icon_width / 2., 0.)
+    };
+    let visuals = ui.style().interact(response);
+    let rect =
+        Rect::from_center_size(center, Vec2::splat(icon_width * 0.75)).expand(visuals.expansion);
+
+    let closed_angle = if style.text_direction.is_rtl() {
+        TAU / 4.
+    } else {
+        -TAU / 4.
+    };
+    let rotation = Rot2::from_angle(remap(openness, 0.0..=1.0, closed_angle..=0.0));
+    let points = [rect.left_top(), rect.right_top(), rect.center_bottom()]
+        .map(|point| rect.center() + rotation * (point - rect.center()))
+        .to_vec();
+    ui.painter().add(Shape::convex_polygon(
+        points,
+        visuals.fg_stroke.color,
+        Stroke::NONE,
+    ));
+}
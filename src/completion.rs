@@ -0,0 +1,184 @@
+use egui::{Context, Id};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context as TaskContext, Poll, Waker},
+};
+
+use crate::PagerTransition;
+
+/// How a transition awaited by [`TransitionHandle`] ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionOutcome {
+    /// The pager has finished the transition to the awaited page and it's now fully shown
+    Completed,
+    /// The pager has finished a transition to a different page
+    Cancelled,
+}
+
+#[derive(Default)]
+struct Shared {
+    outcome: Option<TransitionOutcome>,
+    waker: Option<Waker>,
+}
+
+#[derive(Clone)]
+struct Waiter<Page> {
+    pager_id: Id,
+    target: Page,
+    shared: Arc<Mutex<Shared>>,
+}
+
+fn waiters_id() -> Id {
+    Id::new("egui_transition_animation_waiters")
+}
+
+/// Handle resolved when a pager finishes a transition, see [`TransitionHandle::new`].
+///
+/// It can be either polled with [`TransitionHandle::outcome`] or `.await`ed.
+/// It only uses std primitives, so it works with any executor.
+#[derive(Clone)]
+pub struct TransitionHandle {
+    shared: Arc<Mutex<Shared>>,
+}
+
+impl TransitionHandle {
+    /// Creates a handle resolved when the pager with given `pager_id` finishes the transition to `target`.
+    ///
+    /// If the pager already shows `target` and no transition is running, the handle is resolved immediately.
+    /// If the pager finishes a transition to any other page first, the handle is resolved as [`TransitionOutcome::Cancelled`].
+    ///
+    /// ```
+    /// # async fn load(ctx: egui::Context) {
+    /// use egui_transition_animation::{TransitionHandle, TransitionOutcome};
+    ///
+    /// #[derive(Clone, PartialEq, Eq, PartialOrd)]
+    /// enum Page {
+    ///     Home,
+    ///     Loading,
+    /// }
+    ///
+    /// let loading_shown = TransitionHandle::new(&ctx, egui::Id::new("pager"), Page::Loading);
+    /// if loading_shown.await == TransitionOutcome::Completed {
+    ///     // Start the heavy work
+    /// }
+    /// # }
+    /// ```
+    pub fn new<Page: Sync + Send + Clone + 'static + Eq>(
+        ctx: &Context,
+        pager_id: Id,
+        target: Page,
+    ) -> Self {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+
+        let idle_on_target = PagerTransition::<Page>::load(ctx, pager_id).is_none()
+            && ctx.data_mut(|data| data.get_persisted::<Page>(pager_id.with("pager_current_page")))
+                == Some(target.clone());
+        if idle_on_target {
+            shared.lock().unwrap().outcome = Some(TransitionOutcome::Completed);
+        } else {
+            ctx.data_mut(|data| {
+                data.get_temp_mut_or_default::<Vec<Waiter<Page>>>(waiters_id())
+                    .push(Waiter {
+                        pager_id,
+                        target,
+                        shared: shared.clone(),
+                    })
+            });
+        }
+
+        Self { shared }
+    }
+
+    /// Returns the outcome if the transition has already finished.
+    pub fn outcome(&self) -> Option<TransitionOutcome> {
+        self.shared.lock().unwrap().outcome
+    }
+}
+
+impl Future for TransitionHandle {
+    type Output = TransitionOutcome;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.outcome {
+            Some(outcome) => Poll::Ready(outcome),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Resolves handles waiting for the pager with given `id`, which has just finished a transition to `page`
+pub(crate) fn finish<Page: Sync + Send + Clone + 'static + Eq>(ctx: &Context, id: Id, page: &Page) {
+    let resolved: Vec<(Waiter<Page>, TransitionOutcome)> = ctx.data_mut(|data| {
+        let waiters = data.get_temp_mut_or_default::<Vec<Waiter<Page>>>(waiters_id());
+        let (resolved, pending): (Vec<_>, Vec<_>) = std::mem::take(waiters)
+            .into_iter()
+            // Nobody is waiting for handles that have been dropped
+            .filter(|waiter| Arc::strong_count(&waiter.shared) > 1)
+            .partition(|waiter| waiter.pager_id == id);
+        *waiters = pending;
+        resolved
+            .into_iter()
+            .map(|waiter| {
+                let outcome = if waiter.target == *page {
+                    TransitionOutcome::Completed
+                } else {
+                    TransitionOutcome::Cancelled
+                };
+                (waiter, outcome)
+            })
+            .collect()
+    });
+
+    for (waiter, outcome) in resolved {
+        let mut shared = waiter.shared.lock().unwrap();
+        shared.outcome = Some(outcome);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{pager_id, Scenario};
+
+    #[test]
+    fn resolved_immediately_when_idle_on_target() {
+        let ctx = Context::default();
+        Scenario::Completed.run_between(&ctx, 0., 1., &mut ());
+        let handle = TransitionHandle::new(&ctx, pager_id(), 'A');
+        assert_eq!(handle.outcome(), Some(TransitionOutcome::Completed));
+    }
+
+    #[test]
+    fn resolved_when_transition_finishes() {
+        for scenario in Scenario::ALL {
+            let ctx = Context::default();
+            scenario.run_between(&ctx, 0., 1.1, &mut ());
+            let to_b = TransitionHandle::new(&ctx, pager_id(), 'B');
+            let to_c = TransitionHandle::new(&ctx, pager_id(), 'C');
+            scenario.run_between(&ctx, 1.1, 2.5, &mut ());
+            assert_eq!(to_b.outcome(), None, "{scenario:?}");
+            scenario.run_between(&ctx, 2.5, f64::INFINITY, &mut ());
+
+            let (b, c) = match scenario {
+                Scenario::Completed | Scenario::Skipped => {
+                    (TransitionOutcome::Completed, TransitionOutcome::Cancelled)
+                }
+                Scenario::Cancelled => (TransitionOutcome::Cancelled, TransitionOutcome::Cancelled),
+                Scenario::Retargeted => {
+                    (TransitionOutcome::Cancelled, TransitionOutcome::Completed)
+                }
+            };
+            assert_eq!(to_b.outcome(), Some(b), "{scenario:?}");
+            assert_eq!(to_c.outcome(), Some(c), "{scenario:?}");
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use egui::{
+    Area, Color32, Context, Frame, Id, InnerResponse, Key, Order, Pos2, Rect, Sense, Ui, Vec2,
+};
+use std::hash::Hash;
+
+use crate::{TransitionStyle, TransitionType};
+
+/// Minimal speed (in points per second) of a drag towards the edge that dismisses the sheet
+/// even if it's still mostly open
+const DISMISS_VELOCITY: f32 = 500.;
+
+/// Maximal opacity of the scrim dimming the rest of the screen
+const SCRIM_ALPHA: f32 = 128.;
+
+/// Screen edge a [`Drawer`] slides in from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum DrawerSide {
+    #[default]
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy)]
+enum Edge {
+    Left,
+    Right,
+    Bottom,
+}
+
+impl Edge {
+    /// Unit vector pointing out of the screen through the edge
+    fn outwards(self) -> Vec2 {
+        match self {
+            Self::Left => -Vec2::X,
+            Self::Right => Vec2::X,
+            Self::Bottom => Vec2::Y,
+        }
+    }
+
+    /// Rect of the fully open sheet
+    fn open_rect(self, screen: Rect, size: f32) -> Rect {
+        match self {
+            Self::Left => {
+                Rect::from_min_max(screen.min, Pos2::new(screen.left() + size, screen.bottom()))
+            }
+            Self::Right => {
+                Rect::from_min_max(Pos2::new(screen.right() - size, screen.top()), screen.max)
+            }
+            Self::Bottom => {
+                Rect::from_min_max(Pos2::new(screen.left(), screen.bottom() - size), screen.max)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct SheetState {
+    /// Openness when the running animation started, or the current openness while dragging
+    from: f32,
+    /// Openness the animation goes to
+    to: f32,
+    /// [Time](egui::InputState::time) when the animation started
+    started_at: f64,
+    dragging: bool,
+}
+
+impl SheetState {
+    fn openness(&self, style: &TransitionStyle, time: f64) -> f32 {
+        if self.dragging {
+            return self.from;
+        }
+        // Shorter distance takes shorter time, eg. after the sheet has been dragged
+        let duration = style.duration * (self.to - self.from).abs();
+        let progress = if duration > 0. {
+            ((time - self.started_at) as f32 / duration).min(1.)
+        } else {
+            1.
+        };
+        self.from + (self.to - self.from) * (style.easing)(progress)
+    }
+
+    fn animate(openness: f32, open: bool, time: f64) -> Self {
+        Self {
+            from: openness,
+            to: if open { 1. } else { 0. },
+            started_at: time,
+            dragging: false,
+        }
+    }
+}
+
+/// Shows a sheet sliding in from the `edge` over a scrim
+fn show_sheet<R>(
+    ctx: &Context,
+    id: Id,
+    edge: Edge,
+    size: f32,
+    style: &TransitionStyle,
+    open: &mut bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<InnerResponse<R>> {
+    let time = ctx.input(|input| input.time);
+    let target = if *open { 1. } else { 0. };
+    let mut state = ctx
+        .data(|data| data.get_temp::<SheetState>(id))
+        .unwrap_or(SheetState::animate(target, *open, time));
+    if !state.dragging && state.to != target {
+        state = SheetState::animate(state.openness(style, time), *open, time);
+    }
+    let mut openness = state.openness(style, time);
+    if !*open && openness <= 0. {
+        ctx.data_mut(|data| data.insert_temp(id, state));
+        return None;
+    }
+
+    let screen = ctx.screen_rect();
+    let scrim = Area::new(id.with("scrim"))
+        .order(Order::Foreground)
+        .fixed_pos(screen.min)
+        .show(ctx, |ui| {
+            ui.painter().rect_filled(
+                screen,
+                0.,
+                Color32::from_black_alpha((openness.clamp(0., 1.) * SCRIM_ALPHA) as u8),
+            );
+            ui.allocate_rect(screen, Sense::click())
+        });
+
+    let open_rect = edge.open_rect(screen, size);
+    let outwards = edge.outwards();
+    let frame = Frame::side_top_panel(&ctx.style());
+    let panel = Area::new(id.with("panel"))
+        .order(Order::Foreground)
+        .constrain(false)
+        .fixed_pos(open_rect.min + outwards * size * (1. - openness))
+        .show(ctx, |ui| {
+            let drag = ui.interact(
+                Rect::from_min_size(ui.max_rect().min, open_rect.size()),
+                id.with("drag"),
+                Sense::drag(),
+            );
+            let inner = frame
+                .show(ui, |ui| {
+                    let size = open_rect.size() - frame.total_margin().sum();
+                    ui.set_min_size(size);
+                    ui.set_max_size(size);
+                    add_contents(ui)
+                })
+                .inner;
+            (inner, drag)
+        });
+    ctx.set_sublayer(scrim.response.layer_id, panel.response.layer_id);
+    let (inner, drag) = panel.inner;
+
+    if scrim.inner.clicked() || ctx.input(|input| input.key_pressed(Key::Escape)) {
+        *open = false;
+    }
+
+    if drag.drag_started() {
+        state.dragging = true;
+    }
+    if state.dragging {
+        openness = (openness - drag.drag_delta().dot(outwards) / size).clamp(0., 1.);
+        state.from = openness;
+        if drag.drag_stopped() {
+            let velocity = ctx.input(|input| input.pointer.velocity()).dot(outwards);
+            *open =
+                velocity < -DISMISS_VELOCITY || (velocity < DISMISS_VELOCITY && openness >= 0.5);
+            state = SheetState::animate(openness, *open, time);
+        }
+    }
+    let target = if *open { 1. } else { 0. };
+    if !state.dragging && state.to != target {
+        state = SheetState::animate(openness, *open, time);
+    }
+
+    if !state.dragging && openness != state.to {
+        ctx.request_repaint();
+    }
+    ctx.data_mut(|data| data.insert_temp(id, state));
+    Some(InnerResponse::new(inner, panel.response))
+}
+
+/// Navigation drawer sliding in from the left or right edge of the screen over a dimming scrim.
+///
+/// It's closed by clicking the scrim, pressing Escape or dragging it towards the edge,
+/// either far enough or fast enough.
+/// The animation uses [`easing`](TransitionStyle::easing) and [`duration`](TransitionStyle::duration) of the style,
+/// so it matches the motion of the pagers.
+///
+/// ```
+/// # fn show(ctx: &egui::Context) {
+/// use egui_transition_animation::Drawer;
+///
+/// let mut open = true; // Should be stored in your app's state
+///
+/// Drawer::new("navigation").show(ctx, &mut open, |ui| {
+///     ui.heading("Navigation");
+/// });
+/// # }
+/// ```
+pub struct Drawer {
+    id: Id,
+    side: DrawerSide,
+    width: f32,
+    style: Option<TransitionStyle>,
+}
+
+impl Drawer {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            side: DrawerSide::default(),
+            width: 280.,
+            style: None,
+        }
+    }
+
+    /// Edge of the screen the drawer slides in from. Defaults to [`DrawerSide::Left`].
+    pub fn side(mut self, side: DrawerSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Width of the open drawer, including its margins. Defaults to `280.0`.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::for_context`].
+    ///
+    /// [`TransitionStyle::t_type`] and [`TransitionStyle::amount`] are ignored.
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Shows the drawer if `open` or while it's closing.
+    /// `open` is set to `false` when the user dismisses the drawer.
+    ///
+    /// Returns [`None`] if the drawer is fully closed.
+    pub fn show<R>(
+        self,
+        ctx: &Context,
+        open: &mut bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let style = self
+            .style
+            .unwrap_or_else(|| TransitionStyle::for_context(ctx, TransitionType::HorizontalMove));
+        let edge = match self.side {
+            DrawerSide::Left => Edge::Left,
+            DrawerSide::Right => Edge::Right,
+        };
+        show_sheet(ctx, self.id, edge, self.width, &style, open, add_contents)
+    }
+}
+
+/// Sheet sliding in from the bottom edge of the screen over a dimming scrim.
+///
+/// It behaves the same as [`Drawer`], just from the bottom edge.
+///
+/// ```
+/// # fn show(ctx: &egui::Context) {
+/// use egui_transition_animation::BottomSheet;
+///
+/// let mut open = true; // Should be stored in your app's state
+///
+/// BottomSheet::new("share").height(200.).show(ctx, &mut open, |ui| {
+///     ui.label("Share with...");
+/// });
+/// # }
+/// ```
+pub struct BottomSheet {
+    id: Id,
+    height: f32,
+    style: Option<TransitionStyle>,
+}
+
+impl BottomSheet {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            height: 320.,
+            style: None,
+        }
+    }
+
+    /// Height of the open sheet, including its margins. Defaults to `320.0`.
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::for_context`].
+    ///
+    /// [`TransitionStyle::t_type`] and [`TransitionStyle::amount`] are ignored.
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Shows the sheet if `open` or while it's closing.
+    /// `open` is set to `false` when the user dismisses the sheet.
+    ///
+    /// Returns [`None`] if the sheet is fully closed.
+    pub fn show<R>(
+        self,
+        ctx: &Context,
+        open: &mut bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let style = self
+            .style
+            .unwrap_or_else(|| TransitionStyle::for_context(ctx, TransitionType::VerticalMove));
+        show_sheet(
+            ctx,
+            self.id,
+            Edge::Bottom,
+            self.height,
+            &style,
+            open,
+            add_contents,
+        )
+    }
+}
@@ -0,0 +1,146 @@
+use egui::{Context, Id};
+use std::collections::VecDeque;
+
+/// Maximal number of events of a single page type kept in the buffer.
+/// When exceeded, the oldest events are dropped.
+pub const MAX_BUFFERED_EVENTS: usize = 1024;
+
+/// What happened to a transition, see [`TransitionEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransitionEventKind {
+    /// The target page has changed and the transition started
+    Started,
+    /// The target page has changed while the transition was running
+    Retargeted,
+    /// The transition finished on the page it started from
+    Cancelled,
+    /// The transition finished on the new page
+    Completed,
+}
+
+/// Event of a pager pushed into a per-context buffer, see [`drain_transition_events`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransitionEvent<Page> {
+    /// `id` of the pager
+    pub pager_id: Id,
+    pub kind: TransitionEventKind,
+    /// The page shown before the transition
+    pub from: Page,
+    /// The target page of the transition
+    pub to: Page,
+    /// [Time](egui::InputState::time) when the transition started
+    pub started_at: f64,
+    /// [Time](egui::InputState::time) when the event happened
+    pub time: f64,
+}
+
+fn buffer_id() -> Id {
+    Id::new("egui_transition_animation_events")
+}
+
+/// Takes all events of pagers showing pages of type `Page` since the last call.
+///
+/// Useful for analytics or integration tests, as the pagers themselves don't expose what happened.
+/// Each page type has its own buffer, holding at most [`MAX_BUFFERED_EVENTS`] events.
+pub fn drain_transition_events<Page: Sync + Send + Clone + 'static>(
+    ctx: &Context,
+) -> Vec<TransitionEvent<Page>> {
+    ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<VecDeque<TransitionEvent<Page>>>(buffer_id())
+            .drain(..)
+            .collect()
+    })
+}
+
+/// Pushes an event of the pager with given `id` into the buffer
+pub(crate) fn emit<Page: Sync + Send + Clone + 'static>(
+    ctx: &Context,
+    pager_id: Id,
+    kind: TransitionEventKind,
+    from: &Page,
+    to: &Page,
+) {
+    let time = ctx.input(|input| input.time);
+    let started_at_id = pager_id.with("pager_transition_started_at");
+    let event = TransitionEvent {
+        pager_id,
+        kind,
+        from: from.clone(),
+        to: to.clone(),
+        started_at: if kind == TransitionEventKind::Started {
+            time
+        } else {
+            ctx.data(|data| data.get_temp(started_at_id))
+                .unwrap_or(time)
+        },
+        time,
+    };
+
+    ctx.data_mut(|data| {
+        match kind {
+            TransitionEventKind::Started => data.insert_temp(started_at_id, time),
+            TransitionEventKind::Retargeted => {}
+            TransitionEventKind::Cancelled | TransitionEventKind::Completed => {
+                data.remove::<f64>(started_at_id)
+            }
+        }
+
+        let buffer = data.get_temp_mut_or_default::<VecDeque<TransitionEvent<Page>>>(buffer_id());
+        if buffer.len() >= MAX_BUFFERED_EVENTS {
+            buffer.pop_front();
+        }
+        buffer.push_back(event);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{pager_id, Scenario};
+    use TransitionEventKind::*;
+
+    #[test]
+    fn event_sequences() {
+        for scenario in Scenario::ALL {
+            let ctx = Context::default();
+            scenario.run(&ctx, &mut ());
+            let events = drain_transition_events::<char>(&ctx);
+            let expected: &[_] = match scenario {
+                Scenario::Completed | Scenario::Skipped => {
+                    &[(Started, 'A', 'B'), (Completed, 'A', 'B')]
+                }
+                Scenario::Cancelled => &[
+                    (Started, 'A', 'B'),
+                    (Retargeted, 'A', 'A'),
+                    (Cancelled, 'A', 'A'),
+                ],
+                Scenario::Retargeted => &[
+                    (Started, 'A', 'B'),
+                    (Retargeted, 'A', 'C'),
+                    (Completed, 'A', 'C'),
+                ],
+            };
+            assert_eq!(
+                events
+                    .iter()
+                    .map(|event| (event.kind, event.from, event.to))
+                    .collect::<Vec<_>>(),
+                expected,
+                "{scenario:?}"
+            );
+            // All events belong to the same transition, which started at time 1.0
+            assert!(events
+                .iter()
+                .all(|event| event.pager_id == pager_id() && event.started_at == 1.));
+            assert_eq!(events.last().unwrap().time, 2.5);
+        }
+    }
+
+    #[test]
+    fn drained_events_are_removed() {
+        let ctx = Context::default();
+        Scenario::Completed.run_between(&ctx, 0., 1.1, &mut ());
+        assert_eq!(drain_transition_events::<char>(&ctx).len(), 1);
+        assert!(drain_transition_events::<char>(&ctx).is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use egui::{Ui, Vec2};
+use std::hash::Hash;
+
+use crate::{animated_pager_towards, PagerRet, TransitionStyle};
+
+/// A page laid out in a two-dimensional grid, see [`animated_grid_pager`].
+pub trait GridPage {
+    /// Position of the page in the grid as `(column, row)`.
+    ///
+    /// Columns grow to the right and rows grow downwards.
+    fn grid_position(&self) -> (i32, i32);
+}
+
+/// Direction of the animation between two pages in a grid.
+///
+/// Moving right slides the ui horizontally, moving down slides it vertically
+/// and diagonal moves slide it along the line connecting the two pages.
+fn grid_direction(from: (i32, i32), to: (i32, i32)) -> Vec2 {
+    let delta = Vec2::new((to.0 - from.0) as f32, (to.1 - from.1) as f32);
+    if delta == Vec2::ZERO {
+        Vec2::X
+    } else {
+        delta.normalized()
+    }
+}
+
+/// Shows one of several pages laid out in a two-dimensional grid with transition animation between them.
+///
+/// The axis and direction of the animation is chosen automatically from [`GridPage::grid_position`]
+/// of the original and the target page, so [`TransitionStyle::t_type`] is ignored.
+/// For example, switching to the page on the right is animated like forward [`HorizontalMove`](crate::TransitionType::HorizontalMove),
+/// switching to the page above is animated like backward [`VerticalMove`](crate::TransitionType::VerticalMove).
+///
+/// # Parameters
+///  - `target_page`: Page to show. When changed, it will take some time for the pager to play animation
+///    before actually showing this page.
+///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
+///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
+pub fn animated_grid_pager<Page: Sync + Send + Clone + 'static + Eq + Hash + GridPage, Ret>(
+    ui: &mut Ui,
+    target_page: Page,
+    style: &TransitionStyle,
+    id: egui::Id,
+    add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
+) -> PagerRet<Page, Ret> {
+    animated_pager_towards(
+        ui,
+        target_page,
+        style,
+        id,
+        |original_page, new_page| {
+            grid_direction(original_page.grid_position(), new_page.grid_position())
+        },
+        add_contents,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbours_move_along_one_axis() {
+        assert_eq!(grid_direction((0, 0), (1, 0)), Vec2::X);
+        assert_eq!(grid_direction((1, 0), (0, 0)), -Vec2::X);
+        assert_eq!(grid_direction((0, 0), (0, 1)), Vec2::Y);
+        assert_eq!(grid_direction((0, 1), (0, 0)), -Vec2::Y);
+    }
+
+    #[test]
+    fn distant_pages_give_unit_direction() {
+        assert_eq!(grid_direction((0, 0), (3, 0)), Vec2::X);
+        assert_eq!(grid_direction((2, 5), (2, -5)), -Vec2::Y);
+    }
+
+    #[test]
+    fn diagonal_moves_along_the_connecting_line() {
+        let direction = grid_direction((0, 0), (1, 1));
+        assert!((direction - Vec2::splat(std::f32::consts::FRAC_1_SQRT_2)).length() < 1e-6);
+        let direction = grid_direction((0, 0), (-3, 4));
+        assert!((direction - Vec2::new(-0.6, 0.8)).length() < 1e-6);
+    }
+
+    #[test]
+    fn same_position_moves_forward_horizontally() {
+        assert_eq!(grid_direction((2, 3), (2, 3)), Vec2::X);
+    }
+}
@@ -0,0 +1,88 @@
+use egui::{Context, Id};
+
+/// Decision of a navigation guard, see [`guard_navigation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GuardResult {
+    /// Leave the current page and start the transition
+    Allow,
+    /// Stay on the current page, the target page is reset back to the current page
+    Deny,
+    /// Keep showing the current page and ask again in the next frame,
+    /// eg. while waiting for the user to answer a "Discard changes?" prompt
+    Defer,
+}
+
+/// Lets a guard veto or postpone leaving the current page of a pager.
+///
+/// Call it before the pager and pass the returned page to the pager as `target_page`.
+/// When `target_page` changes, `can_leave` is called with the current page and the new target page
+/// and the pager keeps showing the current page until the guard [allows](GuardResult::Allow) the navigation.
+/// If the guard [denies](GuardResult::Deny) it, `target_page` is reset back to the current page.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{guard_navigation, prelude::*, GuardResult};
+///
+/// #[derive(Clone, PartialEq, Eq, PartialOrd, Hash)]
+/// enum Page {
+///     Editor,
+///     Settings,
+/// }
+///
+/// // Should be stored in your app's state
+/// let mut page = Page::Editor;
+/// let has_unsaved_changes = true;
+///
+/// let pager_id = egui::Id::new("pager");
+/// let page_to_show = guard_navigation(ui.ctx(), pager_id, &mut page, |from, _to| {
+///     if *from != Page::Editor || !has_unsaved_changes {
+///         return GuardResult::Allow;
+///     }
+///     let mut result = GuardResult::Defer;
+///     egui::Window::new("Discard changes?").show(ui.ctx(), |ui| {
+///         if ui.button("Discard").clicked() {
+///             result = GuardResult::Allow;
+///         }
+///         if ui.button("Cancel").clicked() {
+///             result = GuardResult::Deny;
+///         }
+///     });
+///     result
+/// });
+///
+/// animated_pager(ui, page_to_show, &TransitionStyle::horizontal(ui), pager_id, |ui, page| {
+///     // ...
+/// });
+/// # }
+/// ```
+pub fn guard_navigation<Page: Sync + Send + Clone + 'static + Eq>(
+    ctx: &Context,
+    id: Id,
+    target_page: &mut Page,
+    can_leave: impl FnOnce(&Page, &Page) -> GuardResult,
+) -> Page {
+    let allowed_id = id.with("guard_allowed_page");
+    let allowed_page = {
+        let target_page_cloned = target_page.clone();
+        ctx.data_mut(|data| {
+            data.get_persisted_mut_or_insert_with(allowed_id, move || target_page_cloned)
+                .to_owned()
+        })
+    };
+
+    if allowed_page == *target_page {
+        return allowed_page;
+    }
+
+    match can_leave(&allowed_page, target_page) {
+        GuardResult::Allow => {
+            ctx.data_mut(|data| data.insert_persisted(allowed_id, target_page.clone()));
+            target_page.clone()
+        }
+        GuardResult::Deny => {
+            *target_page = allowed_page.clone();
+            allowed_page
+        }
+        GuardResult::Defer => allowed_page,
+    }
+}
@@ -0,0 +1,323 @@
+use egui::{Context, Id, PointerButton, Ui};
+
+/// Bounded list of visited pages with a cursor, shared by [`PagerHistory`] and [`Router`](crate::Router)
+#[derive(Clone, Debug)]
+pub(crate) struct NavigationHistory<Page> {
+    entries: Vec<Page>,
+    position: usize,
+    max_len: usize,
+}
+
+impl<Page: PartialEq> NavigationHistory<Page> {
+    pub(crate) fn new(initial: Page, max_len: usize) -> Self {
+        Self {
+            entries: vec![initial],
+            position: 0,
+            max_len: max_len.max(1),
+        }
+    }
+
+    pub(crate) fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len.max(1);
+        // Drop the oldest entries, then the forward history, but never the current page
+        let overflow = self.entries.len().saturating_sub(self.max_len);
+        let dropped_back = overflow.min(self.position);
+        self.entries.drain(..dropped_back);
+        self.position -= dropped_back;
+        self.entries.truncate(self.max_len);
+    }
+
+    pub(crate) fn current(&self) -> &Page {
+        &self.entries[self.position]
+    }
+
+    /// Navigates to `page`, dropping the forward history. Does nothing if `page` is already the current page.
+    pub(crate) fn push(&mut self, page: Page) {
+        if *self.current() == page {
+            return;
+        }
+        self.entries.truncate(self.position + 1);
+        self.entries.push(page);
+        if self.entries.len() > self.max_len {
+            self.entries.remove(0);
+        }
+        self.position = self.entries.len() - 1;
+    }
+
+    pub(crate) fn can_go_back(&self) -> bool {
+        self.position > 0
+    }
+
+    pub(crate) fn can_go_forward(&self) -> bool {
+        self.position + 1 < self.entries.len()
+    }
+
+    pub(crate) fn back(&mut self) -> bool {
+        if !self.can_go_back() {
+            return false;
+        }
+        self.position -= 1;
+        true
+    }
+
+    pub(crate) fn forward(&mut self) -> bool {
+        if !self.can_go_forward() {
+            return false;
+        }
+        self.position += 1;
+        true
+    }
+}
+
+fn history_id(pager_id: Id) -> Id {
+    pager_id.with("pager_history")
+}
+
+/// Id of the flag telling the pager that the running transition goes back in history
+pub(crate) fn back_flag_id(pager_id: Id) -> Id {
+    pager_id.with("pager_history_back")
+}
+
+/// Back/forward history of pages visited by a pager, stored under the pager's `id`.
+///
+/// Call [`track`](PagerHistory::track) every frame before showing the pager.
+/// Going back in history is animated in reverse to the original navigation
+/// and going forward is animated the same way as the original navigation.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{prelude::*, PagerHistory};
+///
+/// #[derive(Clone, PartialEq, Eq, PartialOrd, Hash)]
+/// enum Page {
+///     Home,
+///     Details,
+/// }
+///
+/// let mut page = Page::Home; // Should be stored in your app's state
+/// let pager_id = egui::Id::new("pager");
+/// let history = PagerHistory::new(pager_id).mouse_buttons(true);
+///
+/// history.track(ui, &mut page);
+/// if ui
+///     .add_enabled(history.can_go_back::<Page>(ui.ctx()), egui::Button::new("⏴ Back"))
+///     .clicked()
+/// {
+///     history.go_back(ui.ctx(), &mut page);
+/// }
+/// animated_pager_forward(ui, page.clone(), &TransitionStyle::horizontal(ui), pager_id, |ui, page| {
+///     // ...
+/// });
+/// # }
+/// ```
+pub struct PagerHistory {
+    pager_id: Id,
+    max_len: usize,
+    mouse_buttons: bool,
+}
+
+impl PagerHistory {
+    /// `pager_id` is the `id` of the pager whose history should be kept.
+    pub fn new(pager_id: Id) -> Self {
+        Self {
+            pager_id,
+            max_len: 100,
+            mouse_buttons: false,
+        }
+    }
+
+    /// Maximal number of pages kept in the history. Defaults to 100.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Whether the back and forward mouse buttons ([`PointerButton::Extra1`] and [`PointerButton::Extra2`])
+    /// navigate through the history. Defaults to `false`.
+    pub fn mouse_buttons(mut self, mouse_buttons: bool) -> Self {
+        self.mouse_buttons = mouse_buttons;
+        self
+    }
+
+    fn with_history<Page: Sync + Send + Clone + 'static + Eq, R>(
+        &self,
+        ctx: &Context,
+        page: &Page,
+        f: impl FnOnce(&mut NavigationHistory<Page>) -> R,
+    ) -> R {
+        ctx.data_mut(|data| {
+            let history = data.get_temp_mut_or_insert_with(history_id(self.pager_id), || {
+                NavigationHistory::new(page.clone(), self.max_len)
+            });
+            history.set_max_len(self.max_len);
+            f(history)
+        })
+    }
+
+    /// Records navigation to `page` and handles the mouse buttons (if [enabled](PagerHistory::mouse_buttons)).
+    ///
+    /// `page` should be the same value you pass as `target_page` to the pager.
+    /// It is changed when navigating by mouse buttons.
+    pub fn track<Page: Sync + Send + Clone + 'static + Eq>(&self, ui: &Ui, page: &mut Page) {
+        if self.mouse_buttons {
+            let (back, forward) = ui.input(|input| {
+                (
+                    input.pointer.button_pressed(PointerButton::Extra1),
+                    input.pointer.button_pressed(PointerButton::Extra2),
+                )
+            });
+            if back {
+                self.go_back(ui.ctx(), page);
+            } else if forward {
+                self.go_forward(ui.ctx(), page);
+            }
+        }
+
+        let navigated = self.with_history(ui.ctx(), page, |history| {
+            let navigated = history.current() != page;
+            history.push(page.clone());
+            navigated
+        });
+        if navigated {
+            ui.ctx()
+                .data_mut(|data| data.insert_temp(back_flag_id(self.pager_id), false));
+        }
+    }
+
+    pub fn can_go_back<Page: Sync + Send + Clone + 'static + Eq>(&self, ctx: &Context) -> bool {
+        ctx.data(|data| {
+            data.get_temp::<NavigationHistory<Page>>(history_id(self.pager_id))
+                .is_some_and(|history| history.can_go_back())
+        })
+    }
+
+    pub fn can_go_forward<Page: Sync + Send + Clone + 'static + Eq>(&self, ctx: &Context) -> bool {
+        ctx.data(|data| {
+            data.get_temp::<NavigationHistory<Page>>(history_id(self.pager_id))
+                .is_some_and(|history| history.can_go_forward())
+        })
+    }
+
+    /// Sets `page` to the previous page in history. Returns `false` if there's nowhere to go back.
+    pub fn go_back<Page: Sync + Send + Clone + 'static + Eq>(
+        &self,
+        ctx: &Context,
+        page: &mut Page,
+    ) -> bool {
+        self.move_in_history(ctx, page, true)
+    }
+
+    /// Sets `page` to the next page in history. Returns `false` if there's nowhere to go forward.
+    pub fn go_forward<Page: Sync + Send + Clone + 'static + Eq>(
+        &self,
+        ctx: &Context,
+        page: &mut Page,
+    ) -> bool {
+        self.move_in_history(ctx, page, false)
+    }
+
+    fn move_in_history<Page: Sync + Send + Clone + 'static + Eq>(
+        &self,
+        ctx: &Context,
+        page: &mut Page,
+        back: bool,
+    ) -> bool {
+        let moved = self.with_history(ctx, page, |history| {
+            let moved = if back {
+                history.back()
+            } else {
+                history.forward()
+            };
+            moved.then(|| history.current().clone())
+        });
+        match moved {
+            Some(new_page) => {
+                *page = new_page;
+                ctx.data_mut(|data| data.insert_temp(back_flag_id(self.pager_id), back));
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(pages: &[u32], max_len: usize) -> NavigationHistory<u32> {
+        let mut history = NavigationHistory::new(pages[0], max_len);
+        for page in &pages[1..] {
+            history.push(*page);
+        }
+        history
+    }
+
+    #[test]
+    fn push_moves_forward() {
+        let history = history(&[1, 2, 3], 10);
+        assert_eq!(history.entries, [1, 2, 3]);
+        assert_eq!(*history.current(), 3);
+        assert!(history.can_go_back());
+        assert!(!history.can_go_forward());
+    }
+
+    #[test]
+    fn push_of_current_page_does_nothing() {
+        let history = history(&[1, 2, 2], 10);
+        assert_eq!(history.entries, [1, 2]);
+    }
+
+    #[test]
+    fn push_drops_forward_history() {
+        let mut history = history(&[1, 2, 3], 10);
+        assert!(history.back());
+        assert!(history.back());
+        assert!(!history.back());
+        history.push(4);
+        assert_eq!(history.entries, [1, 4]);
+        assert_eq!(*history.current(), 4);
+        assert!(!history.forward());
+    }
+
+    #[test]
+    fn push_drops_oldest_entries() {
+        let history = history(&[1, 2, 3, 4], 3);
+        assert_eq!(history.entries, [2, 3, 4]);
+        assert_eq!(*history.current(), 4);
+    }
+
+    #[test]
+    fn back_and_forward() {
+        let mut history = history(&[1, 2, 3], 10);
+        assert!(history.back());
+        assert_eq!(*history.current(), 2);
+        assert!(history.forward());
+        assert_eq!(*history.current(), 3);
+        assert!(!history.forward());
+    }
+
+    #[test]
+    fn set_max_len_drops_oldest_entries() {
+        let mut history = history(&[1, 2, 3, 4], 10);
+        history.set_max_len(2);
+        assert_eq!(history.entries, [3, 4]);
+        assert_eq!(*history.current(), 4);
+        assert!(history.back());
+        assert!(!history.back());
+    }
+
+    #[test]
+    fn set_max_len_keeps_current_page() {
+        let mut history = history(&[1, 2, 3, 4], 10);
+        history.back();
+        history.back();
+        history.set_max_len(2);
+        assert_eq!(history.entries, [2, 3]);
+        assert_eq!(*history.current(), 2);
+
+        history.set_max_len(0);
+        assert_eq!(history.entries, [2]);
+        assert_eq!(*history.current(), 2);
+    }
+}
@@ -0,0 +1,183 @@
+use egui::{emath::easing, Id, Rect, Response, Sense, Ui, Vec2};
+
+use crate::{PageOrder, PagerTransition};
+
+/// Shape of the indicators drawn by [`PageIndicator`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum IndicatorShape {
+    /// Small circles
+    #[default]
+    Dots,
+    /// Wide thin lines
+    Bars,
+}
+
+/// A row of dots (or bars) showing which page of a pager is active.
+///
+/// The active indicator stretches and moves between the dots in sync with the pager's transition.
+/// Clicking a dot navigates to the corresponding page.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::prelude::*;
+/// use egui_transition_animation::PageIndicator;
+///
+/// #[derive(PageOrder, Clone, PartialEq, Eq, Hash, Debug)]
+/// enum Step {
+///     Welcome,
+///     Account,
+///     Done,
+/// }
+///
+/// let mut step = Step::Welcome; // Should be stored in your app's state
+/// let pager_id = egui::Id::new("onboarding");
+///
+/// animated_pager_ordered(ui, step.clone(), &TransitionStyle::horizontal(ui), pager_id, |ui, step| {
+///     ui.label(format!("{step:?}"));
+/// });
+/// PageIndicator::new(pager_id).show(ui, &mut step);
+/// # }
+/// ```
+pub struct PageIndicator {
+    pager_id: Id,
+    shape: IndicatorShape,
+    size: f32,
+}
+
+impl PageIndicator {
+    /// `pager_id` is the `id` of the pager whose state should be shown.
+    pub fn new(pager_id: Id) -> Self {
+        Self {
+            pager_id,
+            shape: IndicatorShape::default(),
+            size: 8.0,
+        }
+    }
+
+    /// Shape of the indicators. Defaults to [`IndicatorShape::Dots`].
+    pub fn shape(mut self, shape: IndicatorShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Height of a single indicator. Defaults to `8.0`.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Shows the indicator for a pager whose pages implement [`PageOrder`].
+    ///
+    /// `page` should be the same value you pass as `target_page` to the pager.
+    /// It is changed when a dot is clicked, in which case the response is marked as [changed](Response::changed).
+    pub fn show<Page: PageOrder + Clone + Send + Sync + 'static>(
+        self,
+        ui: &mut Ui,
+        page: &mut Page,
+    ) -> Response {
+        let all = Page::all();
+        let position = |page: &Page| {
+            all.iter()
+                .position(|other| other.index() == page.index())
+                .unwrap_or(0)
+        };
+
+        let transition = PagerTransition::<Page>::load(ui.ctx(), self.pager_id).map(|transition| {
+            PagerTransition {
+                from: position(&transition.from),
+                to: position(&transition.to),
+                progress: transition.progress,
+            }
+        });
+        let (mut response, clicked) = self.paint(ui, all.len(), position(page), transition);
+        if let Some(clicked) = clicked {
+            *page = all[clicked].clone();
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Shows the indicator for a pager whose pages are indices, eg. `0..count`.
+    ///
+    /// `index` should be the same value you pass as `target_page` to the pager.
+    /// It is changed when a dot is clicked, in which case the response is marked as [changed](Response::changed).
+    pub fn show_index(self, ui: &mut Ui, count: usize, index: &mut usize) -> Response {
+        let transition = PagerTransition::<usize>::load(ui.ctx(), self.pager_id);
+        let (mut response, clicked) = self.paint(ui, count, *index, transition);
+        if let Some(clicked) = clicked {
+            *index = clicked;
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Returns the response of the whole indicator and index of the clicked dot.
+    fn paint(
+        &self,
+        ui: &mut Ui,
+        count: usize,
+        active: usize,
+        transition: Option<PagerTransition<usize>>,
+    ) -> (Response, Option<usize>) {
+        let dot_size = match self.shape {
+            IndicatorShape::Dots => Vec2::splat(self.size),
+            IndicatorShape::Bars => Vec2::new(self.size * 3., self.size / 2.),
+        };
+        let spacing = ui.spacing().item_spacing.x.max(self.size / 2.);
+        let step = dot_size.x + spacing;
+        let desired_size =
+            Vec2::new(step * count as f32 - spacing, dot_size.y.max(self.size)).max(Vec2::ZERO);
+
+        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::hover());
+        let dot_rect = |position: f32| {
+            Rect::from_min_size(
+                egui::pos2(
+                    rect.left() + step * position,
+                    rect.center().y - dot_size.y / 2.,
+                ),
+                dot_size,
+            )
+        };
+
+        let mut clicked = None;
+        let visuals = ui.visuals();
+        let inactive_color = visuals.widgets.inactive.bg_fill;
+        let active_color = visuals.selection.bg_fill;
+        let rounding = dot_size.y / 2.;
+        for i in 0..count {
+            let dot = dot_rect(i as f32);
+            let dot_response = ui.interact(
+                dot.expand(spacing / 2.),
+                response.id.with(i),
+                Sense::click(),
+            );
+            if dot_response.clicked() && i != active {
+                clicked = Some(i);
+            }
+            let color = if dot_response.hovered() {
+                visuals.widgets.hovered.bg_fill
+            } else {
+                inactive_color
+            };
+            ui.painter().rect_filled(dot, rounding, color);
+        }
+
+        // The leading edge of the active indicator moves first and the trailing edge follows,
+        // so the indicator stretches in the middle of the transition.
+        let active_rect = match transition {
+            Some(PagerTransition { from, to, progress }) if from != to => {
+                let leading = easing::cubic_in_out((progress * 2.).min(1.));
+                let trailing = easing::cubic_in_out((progress * 2. - 1.).max(0.));
+                let lerp = |t: f32| from as f32 + (to as f32 - from as f32) * t;
+                dot_rect(lerp(leading)).union(dot_rect(lerp(trailing)))
+            }
+            _ => dot_rect(active as f32),
+        };
+        if count > 0 {
+            ui.painter()
+                .rect_filled(active_rect, rounding, active_color);
+        }
+
+        (response, clicked)
+    }
+}
@@ -0,0 +1,67 @@
+use egui::{Id, Ui};
+use std::hash::Hash;
+
+use crate::{animated_pager_with_direction, PagerRet, TransitionStyle};
+
+/// Shows one of several possible pages with transition animation between them,
+/// for pages that are expensive to clone or don't implement [`Clone`] at all.
+///
+/// Only the small `Key` of each page is stored in egui memory and cloned, the pages themselves stay owned by the caller.
+/// The `Key` is used like `Page` in [`animated_pager`](crate::animated_pager), so it determines the direction of the animation
+/// and scopes widget ids of the pages.
+///
+/// To configure the pager further, use [`AnimatedPager`](crate::AnimatedPager) with the keys as pages
+/// and look the pages up in `add_contents` the same way.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{animated_pager_keyed, TransitionStyle};
+///
+/// struct Document {
+///     text: String,
+/// }
+///
+/// // Should be stored in your app's state
+/// let documents = vec![
+///     Document { text: "Large document".to_owned() },
+///     Document { text: "Even larger document".to_owned() },
+/// ];
+/// let opened = 0;
+///
+/// animated_pager_keyed(
+///     ui,
+///     opened,
+///     |index| &documents[*index],
+///     &TransitionStyle::horizontal(ui),
+///     egui::Id::new("documents"),
+///     |ui, document| ui.label(&document.text),
+/// );
+/// # }
+/// ```
+///
+/// # Parameters
+///  - `target_key`: Key of the page to show. When changed, it will take some time for the pager to play animation
+///    before actually showing this page.
+///  - `page`: Function returning the page with given key.
+///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
+///    The `Page` argument of this function may differ from the page with `target_key` if an animation is running.
+pub fn animated_pager_keyed<'p, Key, Page: ?Sized + 'p, Ret>(
+    ui: &mut Ui,
+    target_key: Key,
+    page: impl Fn(&Key) -> &'p Page,
+    style: &TransitionStyle,
+    id: Id,
+    add_contents: impl FnOnce(&mut Ui, &Page) -> Ret,
+) -> PagerRet<Key, Ret>
+where
+    Key: Sync + Send + Clone + 'static + Eq + Hash + PartialOrd,
+{
+    animated_pager_with_direction(
+        ui,
+        target_key,
+        style,
+        id,
+        |original_key, new_key| original_key < new_key,
+        |ui, key| add_contents(ui, page(&key)),
+    )
+}
@@ -0,0 +1,154 @@
+use egui::{emath::TSTransform, layers::ShapeIdx, Id, InnerResponse, Pos2, Ui, Vec2};
+use std::{collections::HashMap, hash::Hash};
+
+use crate::TransitionStyle;
+
+#[derive(Clone, Copy)]
+struct ItemState {
+    /// Position of the item relative to the scope in the last frame, without the animation offset.
+    ///
+    /// Being relative, it doesn't change when the whole scope moves, eg. when it's scrolled.
+    pos: Vec2,
+    /// Offset of the item when its animation started
+    offset_from: Vec2,
+    /// [Time](egui::InputState::time) when the animation started
+    started_at: f64,
+}
+
+/// Items of an [`animate_layout`] scope, see [`AnimatedLayout::item`].
+pub struct AnimatedLayout<'u> {
+    ui: &'u mut Ui,
+    /// Top left corner of the scope, the item positions are relative to it
+    origin: Pos2,
+    easing: fn(f32) -> f32,
+    duration: f32,
+    time: f64,
+    items: HashMap<Id, ItemState>,
+    shown: HashMap<Id, ItemState>,
+    animating: bool,
+}
+
+impl AnimatedLayout<'_> {
+    /// The [`Ui`] of the scope, for adding contents that shouldn't be animated.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// Adds an item, identified by `key`, whose movement will be animated.
+    ///
+    /// Keys must be unique within the scope and stay the same when the items are reordered.
+    pub fn item<R>(
+        &mut self,
+        key: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let id = Id::new(key);
+        let layer_id = self.ui.layer_id();
+        let start_idx = self.ui.ctx().graphics(|graphics| {
+            graphics
+                .get(layer_id)
+                .map_or(ShapeIdx(0), |list| list.next_idx())
+        });
+
+        let response = self.ui.scope(add_contents);
+        let pos = response.response.rect.min - self.origin;
+
+        let mut state = self.items.get(&id).copied().unwrap_or(ItemState {
+            pos,
+            offset_from: Vec2::ZERO,
+            started_at: self.time,
+        });
+        let mut offset = self.offset(&state);
+        if state.pos != pos {
+            // Start from where the item is painted right now, even if it is in the middle of an animation
+            state.offset_from = offset + (state.pos - pos);
+            state.started_at = self.time;
+            state.pos = pos;
+            offset = state.offset_from;
+        }
+
+        if offset != Vec2::ZERO {
+            self.animating = true;
+            self.ui.ctx().graphics_mut(|graphics| {
+                let list = graphics.entry(layer_id);
+                let end_idx = list.next_idx();
+                list.transform_range(start_idx, end_idx, TSTransform::from_translation(offset));
+            });
+        }
+
+        self.shown.insert(id, state);
+        response
+    }
+
+    /// Current animation offset of an item
+    fn offset(&self, state: &ItemState) -> Vec2 {
+        let progress = if self.duration > 0. {
+            ((self.time - state.started_at) as f32 / self.duration).min(1.)
+        } else {
+            1.
+        };
+        if progress >= 1. {
+            Vec2::ZERO
+        } else {
+            state.offset_from * (1. - (self.easing)(progress))
+        }
+    }
+}
+
+/// Animates movement of items in the scope: when an item changes its position (eg. when a list is reordered),
+/// it's painted sliding from its old position to the new one instead of jumping.
+///
+/// Each item is added with [`AnimatedLayout::item`] and remembered by its key from the previous frame.
+/// The animation uses [`easing`](TransitionStyle::easing) and [`duration`](TransitionStyle::duration) of the `style`.
+/// Only the painting is animated, the items are laid out and interact at their new positions right away.
+/// Moving the whole scope, eg. by scrolling, isn't animated.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{animate_layout, TransitionStyle};
+///
+/// let mut items = vec!["Apples", "Bananas", "Cherries"]; // Should be stored in your app's state
+///
+/// animate_layout(ui, egui::Id::new("fruits"), &TransitionStyle::vertical(ui), |layout| {
+///     if layout.ui().button("Shuffle").clicked() {
+///         items.rotate_left(1);
+///     }
+///     for item in &items {
+///         layout.item(item, |ui| ui.label(*item));
+///     }
+/// });
+/// # }
+/// ```
+pub fn animate_layout<R>(
+    ui: &mut Ui,
+    id: Id,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut AnimatedLayout<'_>) -> R,
+) -> InnerResponse<R> {
+    let time = ui.input(|input| input.time);
+    let items = ui
+        .ctx()
+        .data_mut(|data| data.remove_temp::<HashMap<Id, ItemState>>(id))
+        .unwrap_or_default();
+
+    ui.scope(|ui| {
+        let mut layout = AnimatedLayout {
+            origin: ui.max_rect().min,
+            ui,
+            easing: style.easing,
+            duration: style.duration,
+            time,
+            items,
+            shown: HashMap::new(),
+            animating: false,
+        };
+        let inner = add_contents(&mut layout);
+        // Forget items which aren't shown anymore
+        let shown = layout.shown;
+        if layout.animating {
+            layout.ui.ctx().request_repaint();
+        }
+        layout.ui.ctx().data_mut(|data| data.insert_temp(id, shown));
+        inner
+    })
+}
@@ -8,13 +8,78 @@ use egui::{
 use std::{
     fmt::{self},
     hash::Hash,
-    time::{Duration, Instant},
 };
 
+mod carousel;
+mod collapsing;
+mod completion;
+mod drawer;
+mod events;
+mod grid;
+mod guard;
+mod history;
+mod indicator;
+mod keyed;
+mod layout;
+mod lifecycle;
+mod list;
+mod modal;
+mod page_order;
+mod page_state;
+mod pager;
+mod popup;
+mod reveal;
+mod router;
+mod shared_element;
+mod tabs;
+#[cfg(test)]
+mod test_utils;
+mod text_direction;
+mod toasts;
+mod visibility;
+mod wizard;
+
+pub use carousel::{AnimatedCarousel, CarouselState};
+pub use collapsing::{AnimatedCollapsing, CollapsingResponse};
+pub use completion::{TransitionHandle, TransitionOutcome};
+pub use drawer::{BottomSheet, Drawer, DrawerSide};
+pub use events::{
+    drain_transition_events, TransitionEvent, TransitionEventKind, MAX_BUFFERED_EVENTS,
+};
+pub use grid::{animated_grid_pager, GridPage};
+pub use guard::{guard_navigation, GuardResult};
+pub use history::PagerHistory;
+pub use indicator::{IndicatorShape, PageIndicator};
+pub use keyed::animated_pager_keyed;
+pub use layout::{animate_layout, AnimatedLayout};
+pub use lifecycle::PageLifecycle;
+pub use list::AnimatedList;
+pub use modal::{animated_modal, animated_modal_sliding};
+pub use page_order::PageOrder;
+use page_state::PageScope;
+pub use pager::AnimatedPager;
+pub use popup::{
+    animated_popup_above_or_below_widget, animated_popup_below_widget, animated_tooltip,
+    AnimatedComboBox,
+};
+pub use router::{RouteParams, Router};
+pub use shared_element::shared_element;
+pub use tabs::AnimatedTabs;
+pub use text_direction::TextDirection;
+pub use toasts::Toasts;
+pub use visibility::{animated_visibility, animated_visibility_collapsing};
+pub use wizard::{Wizard, WizardResponse};
+
+/// Derive macro for [`PageOrder`](trait@PageOrder), see its documentation for details.
+#[cfg(feature = "derive")]
+pub use egui_transition_animation_derive::PageOrder;
+
 pub mod prelude {
     pub use super::{
-        animated_pager, animated_pager_backward, animated_pager_forward,
-        animated_pager_with_direction, TransitionStyle, TransitionType,
+        animated_grid_pager, animated_pager, animated_pager_backward, animated_pager_forward,
+        animated_pager_keyed, animated_pager_ordered, animated_pager_towards,
+        animated_pager_with_direction, AnimatedCarousel, AnimatedPager, AnimatedTabs, GridPage,
+        PageIndicator, PageOrder, TextDirection, TransitionStyle, TransitionType, Wizard,
     };
 }
 
@@ -29,14 +94,27 @@ pub enum TransitionType {
 }
 
 impl TransitionType {
-    fn generate_tstransform(&self, amount: f32, origin: Vec2) -> TSTransform {
+    /// Unit vector of the axis along which the ui moves
+    fn axis(&self) -> Vec2 {
         match self {
-            Self::HorizontalMove => TSTransform::from_translation(Vec2::new(amount, 0.)),
-            Self::VerticalMove => TSTransform::from_translation(Vec2::new(0., amount)),
+            Self::HorizontalMove => Vec2::X,
+            Self::VerticalMove => Vec2::Y,
         }
     }
 }
 
+/// Transform zooming `rect` out of `origin`, so that it's `shrink` points narrower.
+///
+/// The `origin` stays in place, eg. the centre of a dialog or the corner of a popup touching its widget.
+pub(crate) fn zoom_tstransform(rect: egui::Rect, origin: egui::Pos2, shrink: f32) -> TSTransform {
+    let scaling = if rect.width() > 0. {
+        (rect.width() - shrink).max(0.) / rect.width()
+    } else {
+        1.
+    };
+    TSTransform::new(origin.to_vec2() * (1. - scaling), scaling)
+}
+
 // TODO: Document
 /// The transition animation used by [`animated_pager`].
 ///
@@ -49,6 +127,34 @@ pub fn page_transition<T>(
     invert_direction: bool,
     add_contents: impl FnOnce(&mut Ui, bool) -> T,
 ) -> T {
+    page_transition_towards(
+        ui,
+        time,
+        style,
+        style.t_type.axis() * if invert_direction { 1. } else { -1. },
+        add_contents,
+    )
+}
+
+/// Same as [`page_transition`], but the ui moves along arbitrary `direction` instead of
+/// the axis of [`TransitionStyle::t_type`].
+///
+/// # Parameters
+///  - `time` - Should be between `0.0` and `1.0`
+///  - `direction` - Direction of the animation going _forward_. Should be a unit vector.
+///    For example [`Vec2::X`] is the same as forward [`TransitionType::HorizontalMove`].
+///    The horizontal component is mirrored in [right-to-left](TextDirection::RightToLeft) layouts.
+pub fn page_transition_towards<T>(
+    ui: &mut Ui,
+    time: f32,
+    style: &TransitionStyle,
+    mut direction: Vec2,
+    add_contents: impl FnOnce(&mut Ui, bool) -> T,
+) -> T {
+    if style.text_direction.is_rtl() {
+        direction.x = -direction.x;
+    }
+
     let anim_state = (style.easing)(time);
     let first_stage = anim_state <= 0.5;
 
@@ -56,12 +162,10 @@ pub fn page_transition<T>(
         -style.amount * anim_state * 2.
     } else {
         style.amount + -style.amount * (2. * anim_state - 1.)
-    } * if invert_direction { 1. } else { -1. };
+    };
 
     ui.with_visual_transform(
-        style
-            .t_type
-            .generate_tstransform(offset_size, Vec2::new(32., 32.)),
+        TSTransform::from_translation(direction * offset_size),
         |ui| add_contents(ui, !first_stage),
     )
     .inner
@@ -92,6 +196,39 @@ impl<Page: fmt::Debug, Ret> PagerRet<Page, Ret> {
     }
 }
 
+/// Transition of a pager that is currently running.
+///
+/// Useful for widgets that should be animated in sync with the pager,
+/// eg. [`PageIndicator`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PagerTransition<Page> {
+    /// The page shown before the transition
+    pub from: Page,
+    /// The page shown after the transition
+    pub to: Page,
+    /// Linear progress of the transition, between `0.0` and `1.0`
+    pub progress: f32,
+}
+
+impl<Page: Clone + Send + Sync + 'static> PagerTransition<Page> {
+    /// Loads the running transition of the pager with given `id`, as of the last time the pager was shown.
+    ///
+    /// Returns [`None`] if no transition is running.
+    pub fn load(ctx: &egui::Context, id: egui::Id) -> Option<Self> {
+        ctx.memory(|mem| mem.data.get_temp(id.with("pager_transition")))
+    }
+}
+
+fn progress_id(id: egui::Id) -> egui::Id {
+    id.with("pager_progress")
+}
+
+/// Linear progress of the running transition of the pager with given `id`,
+/// for code that doesn't know the type of its pages
+pub(crate) fn transition_progress(ctx: &egui::Context, id: egui::Id) -> Option<f32> {
+    ctx.data(|data| data.get_temp(progress_id(id)))
+}
+
 /// Style of a transition.
 ///
 /// You will typically want to construct it with
@@ -108,6 +245,12 @@ pub struct TransitionStyle {
     pub t_type: TransitionType,
     /// How much should [ui](egui::Ui) move during the animation
     pub amount: f32,
+    /// Horizontal transitions are mirrored in [right-to-left](TextDirection::RightToLeft) layouts,
+    /// so going _forward_ slides the content to the right.
+    ///
+    /// The constructors use the one [set for the context](TextDirection::set_for_context), or left-to-right.
+    /// It can be inferred from the layout using [`TextDirection::from_layout`] instead.
+    pub text_direction: TextDirection,
 }
 
 /// # Constructors
@@ -121,6 +264,21 @@ impl TransitionStyle {
             duration: ui.style().animation_time,
             easing: easing::circular_in_out,
             amount: 16.0,
+            text_direction: TextDirection::from_ui(ui),
+        }
+    }
+    /// Same as [`new_with_type`](TransitionStyle::new_with_type), but based on the [style](egui::Context::style)
+    /// of the whole context, for containers that aren't placed in a [`Ui`] (eg. [`Drawer`]).
+    ///
+    /// The [text direction](TransitionStyle::text_direction) is the one [set for the context](TextDirection::set_for_context),
+    /// or left-to-right.
+    pub fn for_context(ctx: &egui::Context, t_type: TransitionType) -> Self {
+        TransitionStyle {
+            t_type,
+            duration: ctx.style().animation_time,
+            easing: easing::circular_in_out,
+            amount: 16.0,
+            text_direction: TextDirection::for_context(ctx).unwrap_or_default(),
         }
     }
     /// Create a new [`TransitionStyle`] animated by shifting horizontally.
@@ -157,7 +315,7 @@ impl TransitionStyle {
 ///    before actually showing this page.
 ///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
 ///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
-pub fn animated_pager_forward<Page: Sync + Send + Clone + 'static + Eq + PartialOrd, Ret>(
+pub fn animated_pager_forward<Page: Sync + Send + Clone + 'static + Eq + Hash + PartialOrd, Ret>(
     ui: &mut Ui,
     target_page: Page,
     style: &TransitionStyle,
@@ -174,7 +332,10 @@ pub fn animated_pager_forward<Page: Sync + Send + Clone + 'static + Eq + Partial
 ///    before actually showing this page.
 ///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
 ///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
-pub fn animated_pager_backward<Page: Sync + Send + Clone + 'static + Eq + PartialOrd, Ret>(
+pub fn animated_pager_backward<
+    Page: Sync + Send + Clone + 'static + Eq + Hash + PartialOrd,
+    Ret,
+>(
     ui: &mut Ui,
     target_page: Page,
     style: &TransitionStyle,
@@ -196,7 +357,7 @@ pub fn animated_pager_backward<Page: Sync + Send + Clone + 'static + Eq + Partia
 ///    before actually showing this page.
 ///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
 ///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
-pub fn animated_pager<Page: Sync + Send + Clone + 'static + Eq + PartialOrd, Ret>(
+pub fn animated_pager<Page: Sync + Send + Clone + 'static + Eq + Hash + PartialOrd, Ret>(
     ui: &mut Ui,
     target_page: Page,
     style: &TransitionStyle,
@@ -212,6 +373,33 @@ pub fn animated_pager<Page: Sync + Send + Clone + 'static + Eq + PartialOrd, Ret
         add_contents,
     )
 }
+/// Shows one of several possible pages with transition animation between them.
+///
+/// Same as [`animated_pager`], but the direction of the animation is determined by [`PageOrder::index`]
+/// instead of [`PartialOrd`], so the pages can be ordered differently than they are declared.
+///
+/// # Parameters
+///  - `target_page`: Page to show. When changed, it will take some time for the pager to play animation
+///    before actually showing this page.
+///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
+///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
+pub fn animated_pager_ordered<Page: Sync + Send + Clone + 'static + Eq + Hash + PageOrder, Ret>(
+    ui: &mut Ui,
+    target_page: Page,
+    style: &TransitionStyle,
+    id: egui::Id,
+    add_contents: impl FnMut(&mut Ui, Page) -> Ret,
+) -> PagerRet<Page, Ret> {
+    animated_pager_with_direction(
+        ui,
+        target_page,
+        style,
+        id,
+        |original_page, new_page| original_page.index() < new_page.index(),
+        add_contents,
+    )
+}
+
 /// Shows one of several possible pages with transition animation between them.
 ///
 /// # Parameters
@@ -225,15 +413,78 @@ pub fn animated_pager<Page: Sync + Send + Clone + 'static + Eq + PartialOrd, Ret
 ///    If you want the animation to always run in the same direction, use [`animated_pager_forward`] or [`animated_pager_backward`].
 ///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
 ///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
-pub fn animated_pager_with_direction<Page: Sync + Send + Clone + 'static + Eq, Ret>(
+pub fn animated_pager_with_direction<Page: Sync + Send + Clone + 'static + Eq + Hash, Ret>(
     ui: &mut Ui,
     target_page: Page,
     style: &TransitionStyle,
     id: egui::Id,
     invert_direction: impl FnOnce(&Page, &Page) -> bool,
     add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
+) -> PagerRet<Page, Ret> {
+    let axis = style.t_type.axis();
+    animated_pager_towards(
+        ui,
+        target_page,
+        style,
+        id,
+        |original_page, new_page| {
+            axis * if invert_direction(original_page, new_page) {
+                1.
+            } else {
+                -1.
+            }
+        },
+        add_contents,
+    )
+}
+
+/// Shows one of several possible pages with transition animation between them.
+///
+/// This is the most general pager, the other pagers are implemented on top of it.
+///
+/// # Parameters
+///  - `target_page`: Page to show. When changed, it will take some time for the pager to play animation
+///    before actually showing this page.
+///  - `direction`: Function that returns direction of the animation (see [`page_transition_towards`]).
+///    It takes the original page as the first argument and target page as the second argument.
+///    [`TransitionStyle::t_type`] is ignored.
+///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
+///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
+pub fn animated_pager_towards<Page: Sync + Send + Clone + 'static + Eq + Hash, Ret>(
+    ui: &mut Ui,
+    target_page: Page,
+    style: &TransitionStyle,
+    id: egui::Id,
+    direction: impl FnOnce(&Page, &Page) -> Vec2,
+    add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
+) -> PagerRet<Page, Ret> {
+    pager_impl(
+        ui,
+        target_page,
+        style,
+        id,
+        direction,
+        &mut (),
+        &PageScope::hashed(),
+        add_contents,
+    )
+}
+
+/// The pager behind all the other pagers and [`AnimatedPager`]
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pager_impl<Page: Sync + Send + Clone + 'static + Eq, Ret>(
+    ui: &mut Ui,
+    target_page: Page,
+    style: &TransitionStyle,
+    id: egui::Id,
+    direction: impl FnOnce(&Page, &Page) -> Vec2,
+    lifecycle: &mut dyn PageLifecycle<Page>,
+    scope: &PageScope<'_, Page>,
+    add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
 ) -> PagerRet<Page, Ret> {
     let animation_length = style.duration;
+    let add_contents =
+        |ui: &mut Ui, page: Page| page_state::show_scoped(ui, id, scope, page, add_contents);
 
     let prev_page = {
         let target_page_cloned = target_page.clone();
@@ -245,24 +496,62 @@ pub fn animated_pager_with_direction<Page: Sync + Send + Clone + 'static + Eq, R
                 .to_owned()
         })
     };
-    let animation_end: Option<Instant> = ui
+    // Time of the frame, so the animation can be driven deterministically through `egui::RawInput::time`
+    let now = ui.input(|input| input.time);
+    let animation_end: Option<f64> = ui
         .ctx()
         .memory(|mem| mem.data.get_temp(id.with("pager_animation_end")));
 
     // If animation is running...
     if let Some(animation_end) = animation_end {
-        let now = Instant::now();
-
         // 0 means we are at the beggining of animation, 1 means we are at the end, .5 means we are at the middle etc.
-        let current_animation_state = 1. - ((animation_end - now).as_secs_f32() / animation_length);
+        let current_animation_state = if animation_length > 0. {
+            1. - ((animation_end - now) as f32 / animation_length)
+        } else {
+            1.
+        };
+
+        let last_target = ui
+            .ctx()
+            .memory(|mem| {
+                mem.data
+                    .get_temp::<PagerTransition<Page>>(id.with("pager_transition"))
+            })
+            .map(|transition| transition.to);
+        if last_target.is_some_and(|last_target| last_target != target_page) {
+            events::emit(
+                ui.ctx(),
+                id,
+                TransitionEventKind::Retargeted,
+                &prev_page,
+                &target_page,
+            );
+        }
 
         // If the animation is done, finish it by setting memory values and display the target page
         if current_animation_state >= 1. {
             ui.ctx().memory_mut(|mem| {
                 mem.data
                     .insert_persisted(id.with("pager_current_page"), target_page.clone());
-                mem.data.remove::<Instant>(id.with("pager_animation_end"));
+                mem.data.remove::<f64>(id.with("pager_animation_end"));
+                mem.data
+                    .remove::<PagerTransition<Page>>(id.with("pager_transition"));
+                mem.data.remove::<f32>(progress_id(id));
+                mem.data.remove::<bool>(history::back_flag_id(id));
             });
+            lifecycle::shown(ui.ctx(), id, &prev_page, &target_page, lifecycle);
+            events::emit(
+                ui.ctx(),
+                id,
+                if prev_page == target_page {
+                    TransitionEventKind::Cancelled
+                } else {
+                    TransitionEventKind::Completed
+                },
+                &prev_page,
+                &target_page,
+            );
+            completion::finish(ui.ctx(), id, &target_page);
 
             let ui_ret = add_contents(ui, target_page.clone());
             return PagerRet {
@@ -272,14 +561,38 @@ pub fn animated_pager_with_direction<Page: Sync + Send + Clone + 'static + Eq, R
             };
         }
 
+        ui.ctx().memory_mut(|mem| {
+            mem.data.insert_temp(
+                id.with("pager_transition"),
+                PagerTransition {
+                    from: prev_page.clone(),
+                    to: target_page.clone(),
+                    progress: current_animation_state,
+                },
+            );
+            mem.data
+                .insert_temp(progress_id(id), current_animation_state);
+        });
         ui.ctx().request_repaint();
 
-        return page_transition(
+        let history_back = ui
+            .ctx()
+            .data(|data| data.get_temp::<bool>(history::back_flag_id(id)))
+            .unwrap_or(false);
+        return page_transition_towards(
             ui,
             current_animation_state,
             style,
-            invert_direction(&prev_page, &target_page),
+            if history_back {
+                // Reverse of the navigation that led from the target page to the previous one
+                -direction(&target_page, &prev_page)
+            } else {
+                direction(&prev_page, &target_page)
+            },
             |ui, show_second_page| {
+                if show_second_page {
+                    lifecycle::enter(ui.ctx(), id, &prev_page, &target_page, lifecycle);
+                }
                 let show_page = if show_second_page {
                     target_page.clone()
                 } else {
@@ -305,9 +618,26 @@ pub fn animated_pager_with_direction<Page: Sync + Send + Clone + 'static + Eq, R
         ui.ctx().memory_mut(|mem| {
             mem.data.insert_temp(
                 id.with("pager_animation_end"),
-                Instant::now() + Duration::from_millis((animation_length * 1000.0) as u64),
-            )
+                now + f64::from(animation_length),
+            );
+            mem.data.insert_temp(
+                id.with("pager_transition"),
+                PagerTransition {
+                    from: prev_page.clone(),
+                    to: target_page.clone(),
+                    progress: 0.,
+                },
+            );
+            mem.data.insert_temp(progress_id(id), 0f32);
         });
+        lifecycle::exit(ui.ctx(), id, &prev_page, lifecycle);
+        events::emit(
+            ui.ctx(),
+            id,
+            TransitionEventKind::Started,
+            &prev_page,
+            &target_page,
+        );
 
         let ui_ret = add_contents(ui, prev_page.clone());
         ui.ctx().request_repaint();
@@ -0,0 +1,131 @@
+use egui::{Context, Id};
+
+/// Callbacks invoked by a pager when its pages appear or disappear, see [`AnimatedPager::lifecycle`](crate::AnimatedPager::lifecycle).
+///
+/// Each callback is invoked exactly once per transition, so it's a good place
+/// to start or stop resources (eg. file watchers or polling) needed only by a visible page.
+/// All methods do nothing by default.
+///
+/// Order of the callbacks during a transition from page `A` to page `B`:
+///  1. `on_exit(A)` when the transition starts
+///  2. `on_hidden(A)` and `on_enter(B)` in the middle of the transition, when `B` replaces `A`
+///  3. `on_shown(B)` when the transition finishes
+///
+/// If the target page changes after `on_enter` has been called for the previous target,
+/// the previous target gets `on_exit` and `on_hidden` before the new target gets `on_enter`.
+pub trait PageLifecycle<Page> {
+    /// The page started to disappear
+    fn on_exit(&mut self, _page: &Page) {}
+    /// The page has disappeared
+    fn on_hidden(&mut self, _page: &Page) {}
+    /// The page started to appear
+    fn on_enter(&mut self, _page: &Page) {}
+    /// The page has fully appeared
+    fn on_shown(&mut self, _page: &Page) {}
+}
+
+/// Ignores all events
+impl<Page> PageLifecycle<Page> for () {}
+
+/// Which lifecycle events have already been invoked during the running transition
+#[derive(Clone)]
+enum LifecycleStage<Page> {
+    /// `on_exit` of the original page has been invoked
+    Exiting,
+    /// `on_enter` of the given page has been invoked
+    Entering(Page),
+}
+
+fn stage_id(id: Id) -> Id {
+    id.with("pager_lifecycle_stage")
+}
+
+/// Invoked when a transition from `from` starts
+pub(crate) fn exit<Page: Sync + Send + Clone + 'static>(
+    ctx: &Context,
+    id: Id,
+    from: &Page,
+    lifecycle: &mut dyn PageLifecycle<Page>,
+) {
+    lifecycle.on_exit(from);
+    ctx.data_mut(|data| data.insert_temp(stage_id(id), LifecycleStage::<Page>::Exiting));
+}
+
+/// Invoked every frame of the second half of a transition from `from` to `to`
+pub(crate) fn enter<Page: Sync + Send + Clone + 'static + Eq>(
+    ctx: &Context,
+    id: Id,
+    from: &Page,
+    to: &Page,
+    lifecycle: &mut dyn PageLifecycle<Page>,
+) {
+    match ctx.data(|data| data.get_temp::<LifecycleStage<Page>>(stage_id(id))) {
+        Some(LifecycleStage::Entering(entered)) if entered == *to => return,
+        // The target has changed after it started to appear
+        Some(LifecycleStage::Entering(entered)) => {
+            lifecycle.on_exit(&entered);
+            lifecycle.on_hidden(&entered);
+        }
+        Some(LifecycleStage::Exiting) | None => lifecycle.on_hidden(from),
+    }
+    lifecycle.on_enter(to);
+    ctx.data_mut(|data| data.insert_temp(stage_id(id), LifecycleStage::Entering(to.clone())));
+}
+
+/// Invoked when a transition from `from` to `to` finishes
+pub(crate) fn shown<Page: Sync + Send + Clone + 'static + Eq>(
+    ctx: &Context,
+    id: Id,
+    from: &Page,
+    to: &Page,
+    lifecycle: &mut dyn PageLifecycle<Page>,
+) {
+    // The transition may finish without rendering any frame of its second half
+    enter(ctx, id, from, to, lifecycle);
+    lifecycle.on_shown(to);
+    ctx.data_mut(|data| data.remove::<LifecycleStage<Page>>(stage_id(id)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::Scenario;
+
+    /// Records the invoked callbacks
+    #[derive(Default)]
+    struct Recorder(Vec<String>);
+
+    impl PageLifecycle<char> for Recorder {
+        fn on_exit(&mut self, page: &char) {
+            self.0.push(format!("exit {page}"));
+        }
+        fn on_hidden(&mut self, page: &char) {
+            self.0.push(format!("hidden {page}"));
+        }
+        fn on_enter(&mut self, page: &char) {
+            self.0.push(format!("enter {page}"));
+        }
+        fn on_shown(&mut self, page: &char) {
+            self.0.push(format!("shown {page}"));
+        }
+    }
+
+    #[test]
+    fn each_callback_once_per_transition() {
+        for scenario in Scenario::ALL {
+            let ctx = Context::default();
+            let mut recorder = Recorder::default();
+            scenario.run(&ctx, &mut recorder);
+            let expected: &[&str] = match scenario {
+                Scenario::Completed | Scenario::Skipped => {
+                    &["exit A", "hidden A", "enter B", "shown B"]
+                }
+                Scenario::Cancelled => &["exit A", "hidden A", "enter A", "shown A"],
+                Scenario::Retargeted => &[
+                    "exit A", "hidden A", "enter B", "exit B", "hidden B", "enter C", "shown C",
+                ],
+            };
+            assert_eq!(recorder.0, expected, "{scenario:?}");
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use egui::{Id, Response, Ui};
+use std::hash::Hash;
+
+use crate::{reveal::Reveal, TransitionStyle};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Phase {
+    /// Appearing since the given [time](egui::InputState::time)
+    Entering(f64),
+    Shown,
+    /// Disappearing since the given [time](egui::InputState::time)
+    Exiting(f64),
+}
+
+#[derive(Clone)]
+struct Entry<Key> {
+    key: Key,
+    phase: Phase,
+}
+
+/// Shows a list of items whose insertion and removal is animated.
+///
+/// The items are identified by keys, which are compared with the keys from the previous frame.
+/// New items appear and removed items disappear by sliding along the axis of [`TransitionStyle::t_type`],
+/// fading and collapsing. Removed items are kept in their place and shown until they disappear,
+/// so the function adding an item must be able to show it from its key alone.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::AnimatedList;
+///
+/// let mut todos = vec!["Buy milk".to_owned(), "Walk the dog".to_owned()]; // Should be stored in your app's state
+/// let mut done = None;
+///
+/// AnimatedList::new("todos").show(ui, todos.iter().cloned(), |ui, todo| {
+///     if ui.checkbox(&mut false, todo).clicked() {
+///         done = Some(todo.clone());
+///     }
+/// });
+/// todos.retain(|todo| Some(todo) != done.as_ref());
+/// # }
+/// ```
+pub struct AnimatedList {
+    id: Id,
+    style: Option<TransitionStyle>,
+    slide: bool,
+    fade: bool,
+    collapse: bool,
+}
+
+impl AnimatedList {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            style: None,
+            slide: true,
+            fade: true,
+            collapse: true,
+        }
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::horizontal`].
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Whether the items slide in and out along the axis of [`TransitionStyle::t_type`]. Defaults to `true`.
+    pub fn slide(mut self, slide: bool) -> Self {
+        self.slide = slide;
+        self
+    }
+
+    /// Whether the items fade in and out. Defaults to `true`.
+    pub fn fade(mut self, fade: bool) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Whether the space taken by the items grows and shrinks, so the other items move smoothly. Defaults to `true`.
+    pub fn collapse(mut self, collapse: bool) -> Self {
+        self.collapse = collapse;
+        self
+    }
+
+    /// Shows the list.
+    ///
+    /// # Parameters
+    ///  - `keys`: Keys of the items to show, in order. Keys must be unique.
+    ///  - `add_item`: Function that shows the item with given key.
+    ///    It's also called for removed items until they disappear, their ui is disabled.
+    pub fn show<Key: Sync + Send + Clone + 'static + Eq + Hash>(
+        self,
+        ui: &mut Ui,
+        keys: impl IntoIterator<Item = Key>,
+        mut add_item: impl FnMut(&mut Ui, &Key),
+    ) -> Response {
+        let style = self
+            .style
+            .unwrap_or_else(|| TransitionStyle::horizontal(ui));
+        let reveal = Reveal::new(&style, self.slide, self.fade, self.collapse);
+        let time = ui.input(|input| input.time);
+        let entries_id = self.id.with("list_entries");
+
+        let previous = ui
+            .ctx()
+            .data_mut(|data| data.remove_temp::<Vec<Entry<Key>>>(entries_id));
+        let mut entries: Vec<Entry<Key>> = keys
+            .into_iter()
+            .map(|key| Entry {
+                key,
+                // Items shown in the first frame don't appear
+                phase: if previous.is_some() {
+                    Phase::Entering(time)
+                } else {
+                    Phase::Shown
+                },
+            })
+            .collect();
+
+        let progress = |started_at: f64| {
+            if style.duration > 0. {
+                ((time - started_at) as f32 / style.duration).min(1.)
+            } else {
+                1.
+            }
+        };
+        // Start time of the opposite animation which reaches the current visibility right now,
+        // so an item changing its direction mid-animation continues from where it is.
+        // Assumes the easing is symmetric, like in-out easings are.
+        let reversed = |started_at: f64| {
+            time - f64::from(1. - progress(started_at)) * f64::from(style.duration)
+        };
+
+        // Keep the phases of the items from the previous frame and put the removed ones back in their place
+        let mut insert_at = 0;
+        for old in previous.unwrap_or_default() {
+            match entries.iter().position(|entry| entry.key == old.key) {
+                Some(position) => {
+                    entries[position].phase = match old.phase {
+                        Phase::Exiting(started_at) => Phase::Entering(reversed(started_at)),
+                        phase => phase,
+                    };
+                    insert_at = position + 1;
+                }
+                None => {
+                    let phase = match old.phase {
+                        Phase::Exiting(started_at) => Phase::Exiting(started_at),
+                        Phase::Entering(started_at) => Phase::Exiting(reversed(started_at)),
+                        Phase::Shown => Phase::Exiting(time),
+                    };
+                    entries.insert(
+                        insert_at,
+                        Entry {
+                            key: old.key,
+                            phase,
+                        },
+                    );
+                    insert_at += 1;
+                }
+            }
+        }
+
+        let mut animating = false;
+        let response = ui
+            .scope(|ui| {
+                entries.retain_mut(|entry| {
+                    let visibility = match entry.phase {
+                        Phase::Entering(started_at) if progress(started_at) >= 1. => {
+                            entry.phase = Phase::Shown;
+                            1.
+                        }
+                        Phase::Entering(started_at) => (style.easing)(progress(started_at)),
+                        Phase::Shown => 1.,
+                        Phase::Exiting(started_at) if progress(started_at) >= 1. => return false,
+                        Phase::Exiting(started_at) => 1. - (style.easing)(progress(started_at)),
+                    };
+                    animating |= entry.phase != Phase::Shown;
+
+                    let item_id = self.id.with(&entry.key);
+                    let exiting = matches!(entry.phase, Phase::Exiting(_));
+                    reveal.show(ui, item_id, visibility, |ui| {
+                        if exiting {
+                            ui.disable();
+                        }
+                        add_item(ui, &entry.key);
+                    });
+                    true
+                });
+            })
+            .response;
+
+        if animating {
+            ui.ctx().request_repaint();
+        }
+        ui.ctx()
+            .data_mut(|data| data.insert_temp(entries_id, entries));
+        response
+    }
+}
@@ -0,0 +1,118 @@
+use egui::{
+    emath::TSTransform, layers::ShapeIdx, Color32, Context, Frame, Id, Modal, ModalResponse, Ui,
+};
+
+use crate::{zoom_tstransform, TransitionStyle};
+
+/// Opacity of the backdrop of a fully shown modal, the same as [`Modal`]'s default
+const BACKDROP_ALPHA: f32 = 100.;
+
+/// Shows a centred [`Modal`] dialog, which zooms in and fades in over an appearing backdrop when `open`
+/// becomes `true` and does the reverse when it becomes `false`.
+///
+/// The dialog grows by [`TransitionStyle::amount`] on each side while appearing
+/// and uses the [`easing`](TransitionStyle::easing) and [`duration`](TransitionStyle::duration) of the style.
+/// It's still shown (disabled) during the exit transition and blocks input to the layers underneath
+/// the whole time it's shown. Use [`ModalResponse::should_close`] to close it by clicking the backdrop or pressing Escape.
+///
+/// Returns [`None`] if the dialog is fully hidden.
+///
+/// ```
+/// # fn show(ctx: &egui::Context, ui: &mut egui::Ui) {
+/// use egui_transition_animation::{animated_modal, TransitionStyle};
+///
+/// let mut open = true; // Should be stored in your app's state
+///
+/// let response = animated_modal(ctx, egui::Id::new("confirm"), open, &TransitionStyle::vertical(ui), |ui| {
+///     ui.heading("Delete the file?");
+///     ui.button("Delete").clicked()
+/// });
+/// if response.is_some_and(|response| response.inner || response.should_close()) {
+///     open = false;
+/// }
+/// # }
+/// ```
+pub fn animated_modal<R>(
+    ctx: &Context,
+    id: Id,
+    open: bool,
+    style: &TransitionStyle,
+    contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<ModalResponse<R>> {
+    modal_impl(ctx, id, open, style, false, contents)
+}
+
+/// Same as [`animated_modal`], but the dialog slides in along the axis of [`TransitionStyle::t_type`]
+/// by [`TransitionStyle::amount`] instead of zooming in, eg. up from below for [`TransitionType::VerticalMove`](crate::TransitionType::VerticalMove).
+pub fn animated_modal_sliding<R>(
+    ctx: &Context,
+    id: Id,
+    open: bool,
+    style: &TransitionStyle,
+    contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<ModalResponse<R>> {
+    modal_impl(ctx, id, open, style, true, contents)
+}
+
+fn modal_impl<R>(
+    ctx: &Context,
+    id: Id,
+    open: bool,
+    style: &TransitionStyle,
+    slide: bool,
+    contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<ModalResponse<R>> {
+    let visibility = ctx.animate_bool_with_time_and_easing(
+        id.with("visibility"),
+        open,
+        style.duration,
+        style.easing,
+    );
+    if !open && visibility <= 0. {
+        return None;
+    }
+
+    let response = Modal::new(id)
+        .frame(Frame::none())
+        .backdrop_color(Color32::from_black_alpha(
+            (visibility.clamp(0., 1.) * BACKDROP_ALPHA) as u8,
+        ))
+        .show(ctx, |ui| {
+            let layer_id = ui.layer_id();
+            let start_idx = ui.ctx().graphics(|graphics| {
+                graphics
+                    .get(layer_id)
+                    .map_or(ShapeIdx(0), |list| list.next_idx())
+            });
+
+            let dialog = ui.scope(|ui| {
+                ui.multiply_opacity(visibility.clamp(0., 1.));
+                if !open {
+                    ui.disable();
+                }
+                Frame::popup(ui.style()).show(ui, contents).inner
+            });
+
+            let rect = dialog.response.rect;
+            let hidden = 1. - visibility;
+            let transform = if slide {
+                let mut direction = style.t_type.axis();
+                if style.text_direction.is_rtl() {
+                    direction.x = -direction.x;
+                }
+                TSTransform::from_translation(direction * style.amount * hidden)
+            } else {
+                // Scale around the centre of the dialog
+                zoom_tstransform(rect, rect.center(), 2. * style.amount * hidden)
+            };
+            if transform != TSTransform::IDENTITY {
+                ui.ctx().graphics_mut(|graphics| {
+                    let list = graphics.entry(layer_id);
+                    let end_idx = list.next_idx();
+                    list.transform_range(start_idx, end_idx, transform);
+                });
+            }
+            dialog.inner
+        });
+    Some(response)
+}
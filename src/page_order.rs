@@ -0,0 +1,53 @@
+/// Defines the order of pages, eg. for determining direction of the animation
+/// in [`animated_pager_ordered`](crate::animated_pager_ordered).
+///
+/// Unlike [`PartialOrd`], the order doesn't have to match the order of declaration
+/// and the pages can also be enumerated.
+///
+/// With the `derive` feature (enabled by default), it can be derived for both fieldless and data-carrying enums:
+///
+/// ```
+/// use egui_transition_animation::PageOrder;
+///
+/// #[derive(PageOrder, Debug, PartialEq)]
+/// enum Page {
+///     Home,
+///     #[page_order(index = 3)]
+///     Settings,
+///     #[page_order(index = 1)]
+///     Profile { user: String },
+///     Notifications(u32),
+/// }
+///
+/// assert_eq!(Page::Settings.index(), 3);
+/// assert_eq!(Page::Notifications(7).index(), 2);
+/// assert_eq!(Page::Home.next(), Some(Page::Profile { user: String::new() }));
+/// assert_eq!(Page::Home.prev(), None);
+/// ```
+///
+/// Fields of data-carrying variants are filled with [`Default::default`] in [`all`](PageOrder::all),
+/// [`next`](PageOrder::next) and [`prev`](PageOrder::prev).
+pub trait PageOrder: Sized {
+    /// Position of the page. Pages with lower index come first.
+    ///
+    /// Indices don't have to be contiguous, but should be unique.
+    fn index(&self) -> usize;
+
+    /// All pages, sorted by their [`index`](PageOrder::index).
+    fn all() -> Vec<Self>;
+
+    /// The page following this one, or [`None`] if this is the last page.
+    fn next(&self) -> Option<Self> {
+        let index = self.index();
+        Self::all().into_iter().find(|page| page.index() > index)
+    }
+
+    /// The page preceding this one, or [`None`] if this is the first page.
+    fn prev(&self) -> Option<Self> {
+        let index = self.index();
+        Self::all()
+            .into_iter()
+            .rev()
+            .find(|page| page.index() < index)
+    }
+}
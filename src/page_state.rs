@@ -0,0 +1,73 @@
+use egui::{Context, Id, Ui};
+use std::{collections::VecDeque, hash::Hash};
+
+pub(crate) type PageKey<'a, Page> = Box<dyn Fn(&Page) -> Id + 'a>;
+
+/// How a pager scopes the ids of widgets on its pages
+pub(crate) struct PageScope<'a, Page> {
+    pub(crate) key: PageKey<'a, Page>,
+    /// Number of most recently shown pages whose state isn't reset, never reset if [`None`]
+    pub(crate) reset_state_beyond: Option<usize>,
+}
+
+impl<Page: Hash> PageScope<'_, Page> {
+    /// Scope keyed by the page itself, never resetting the state of pages
+    pub(crate) fn hashed() -> Self {
+        Self {
+            key: Box::new(|page| Id::new(page)),
+            reset_state_beyond: None,
+        }
+    }
+}
+
+/// Most recently shown pages of a pager with their generations
+#[derive(Clone, Default)]
+struct RecentPages {
+    recent: VecDeque<(Id, u64)>,
+    /// Generation of the next page which isn't recent.
+    /// Counting globally means reset pages don't have to be remembered to get a new generation.
+    next_generation: u64,
+}
+
+fn recent_pages_id(pager_id: Id) -> Id {
+    pager_id.with("pager_recent_pages")
+}
+
+/// Marks the page with given `key` as shown and returns its generation,
+/// which changes whenever the page falls out of the `recent_pages` most recent ones, so it starts with fresh state
+fn touch(ctx: &Context, pager_id: Id, key: Id, recent_pages: usize) -> u64 {
+    ctx.data_mut(|data| {
+        let recent = data.get_temp_mut_or_default::<RecentPages>(recent_pages_id(pager_id));
+        let recent_generation = recent
+            .recent
+            .iter()
+            .position(|(recent, _)| *recent == key)
+            .and_then(|position| recent.recent.remove(position))
+            .map(|(_, generation)| generation);
+        let generation = recent_generation.unwrap_or_else(|| {
+            // Either shown for the first time, or reset since it was shown last time
+            recent.next_generation += 1;
+            recent.next_generation
+        });
+        recent.recent.push_front((key, generation));
+        recent.recent.truncate(recent_pages.max(1));
+        generation
+    })
+}
+
+/// Shows `page` in an id scope derived from its key, so its widgets keep their state
+/// (scroll offsets, collapsing headers, text cursors...) when the page is shown again
+pub(crate) fn show_scoped<Page, Ret>(
+    ui: &mut Ui,
+    pager_id: Id,
+    scope: &PageScope<'_, Page>,
+    page: Page,
+    add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
+) -> Ret {
+    let key = (scope.key)(&page);
+    let generation = scope.reset_state_beyond.map_or(0, |recent_pages| {
+        touch(ui.ctx(), pager_id, key, recent_pages)
+    });
+    ui.push_id(key.with(generation), |ui| add_contents(ui, page))
+        .inner
+}
@@ -0,0 +1,170 @@
+use egui::{Id, Ui, Vec2};
+
+use std::hash::Hash;
+
+use crate::{
+    page_state::{PageKey, PageScope},
+    pager_impl, PageLifecycle, PagerRet, TransitionStyle,
+};
+
+type Direction<'a, Page> = Box<dyn FnOnce(&Page, &Page) -> Vec2 + 'a>;
+
+/// Shows one of several possible pages with transition animation between them.
+///
+/// Builder version of the `animated_pager_*` functions, allowing additional configuration
+/// like [lifecycle callbacks](AnimatedPager::lifecycle).
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{prelude::*, PageLifecycle};
+///
+/// #[derive(Clone, PartialEq, Eq, PartialOrd, Hash, Debug)]
+/// enum Page {
+///     Files,
+///     Logs,
+/// }
+///
+/// struct Watchers;
+///
+/// impl PageLifecycle<Page> for Watchers {
+///     fn on_enter(&mut self, page: &Page) {
+///         println!("Start watching {page:?}");
+///     }
+///     fn on_hidden(&mut self, page: &Page) {
+///         println!("Stop watching {page:?}");
+///     }
+/// }
+///
+/// // Should be stored in your app's state
+/// let page = Page::Files;
+/// let mut watchers = Watchers;
+///
+/// let style = TransitionStyle::horizontal(ui);
+/// AnimatedPager::new(egui::Id::new("pager"), &style)
+///     .direction(|from, to| from < to)
+///     .lifecycle(&mut watchers)
+///     .show(ui, page.clone(), |ui, page| ui.label(format!("{page:?}")));
+/// # }
+/// ```
+pub struct AnimatedPager<'a, Page> {
+    id: Id,
+    style: &'a TransitionStyle,
+    direction: Option<Direction<'a, Page>>,
+    lifecycle: Option<&'a mut dyn PageLifecycle<Page>>,
+    page_key: PageKey<'a, Page>,
+    reset_state_beyond: Option<usize>,
+}
+
+impl<'a, Page: Sync + Send + Clone + 'static + Eq + Hash> AnimatedPager<'a, Page> {
+    /// Creates a pager showing each page in an id scope keyed by the page itself.
+    pub fn new(id: Id, style: &'a TransitionStyle) -> Self {
+        Self::with_key(id, style, Box::new(|page| Id::new(page)))
+    }
+}
+
+impl<'a, Page: Sync + Send + Clone + 'static + Eq> AnimatedPager<'a, Page> {
+    /// Creates a pager for pages which don't implement [`Hash`].
+    /// Each page is shown in an id scope keyed by the key returned by `page_key`, see [`page_key`](AnimatedPager::page_key).
+    pub fn with_page_key<Key: Hash>(
+        id: Id,
+        style: &'a TransitionStyle,
+        page_key: impl Fn(&Page) -> Key + 'a,
+    ) -> Self {
+        Self::with_key(id, style, Box::new(move |page| Id::new(page_key(page))))
+    }
+
+    fn with_key(id: Id, style: &'a TransitionStyle, page_key: PageKey<'a, Page>) -> Self {
+        Self {
+            id,
+            style,
+            direction: None,
+            lifecycle: None,
+            page_key,
+            reset_state_beyond: None,
+        }
+    }
+
+    /// Function that returns `true` for forward direction of animation and `false` for backward direction of animation.
+    /// It takes the original page as the first argument and target page as the second argument.
+    ///
+    /// See [`animated_pager_with_direction`](crate::animated_pager_with_direction). The animation goes forward by default.
+    pub fn direction(mut self, invert_direction: impl FnOnce(&Page, &Page) -> bool + 'a) -> Self {
+        let axis = self.style.t_type.axis();
+        self.direction = Some(Box::new(move |original_page, new_page| {
+            axis * if invert_direction(original_page, new_page) {
+                1.
+            } else {
+                -1.
+            }
+        }));
+        self
+    }
+
+    /// Function that returns direction of the animation, ignoring [`TransitionStyle::t_type`].
+    ///
+    /// See [`animated_pager_towards`](crate::animated_pager_towards).
+    pub fn direction_towards(mut self, direction: impl FnOnce(&Page, &Page) -> Vec2 + 'a) -> Self {
+        self.direction = Some(Box::new(direction));
+        self
+    }
+
+    /// Callbacks invoked when pages appear or disappear.
+    pub fn lifecycle(mut self, lifecycle: &'a mut impl PageLifecycle<Page>) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    /// Shows each page in an id scope derived from the key returned by `page_key` instead of the page itself,
+    /// so the state of its widgets (scroll offsets, collapsing headers, text cursors...) survives navigation
+    /// and pages with different keys don't share the state of widgets with the same id.
+    ///
+    /// Pages with equal keys share the state.
+    pub fn page_key<Key: Hash>(mut self, page_key: impl Fn(&Page) -> Key + 'a) -> Self {
+        self.page_key = Box::new(move |page| Id::new(page_key(page)));
+        self
+    }
+
+    /// Resets the state of widgets on pages which aren't among the `recent_pages` most recently shown pages.
+    /// Such pages start with fresh state when shown again.
+    ///
+    /// This doesn't free any memory, it increases memory use instead: a reset page only gets new ids
+    /// for its widgets, while egui keeps the old state of the widgets in [`egui::Memory`].
+    /// Use it when pages have to forget their state, not to limit memory use.
+    ///
+    /// Disabled by default.
+    pub fn reset_state_beyond(mut self, recent_pages: usize) -> Self {
+        self.reset_state_beyond = Some(recent_pages);
+        self
+    }
+
+    /// Shows the pager.
+    ///
+    /// # Parameters
+    ///  - `target_page`: Page to show. When changed, it will take some time for the pager to play animation
+    ///    before actually showing this page.
+    ///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
+    ///    The `Page` argument of this function may differ from the provided `target_page` if an animation is running.
+    pub fn show<Ret>(
+        self,
+        ui: &mut Ui,
+        target_page: Page,
+        add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
+    ) -> PagerRet<Page, Ret> {
+        let axis = self.style.t_type.axis();
+        let direction = self.direction.unwrap_or_else(|| Box::new(move |_, _| axis));
+        let scope = PageScope {
+            key: self.page_key,
+            reset_state_beyond: self.reset_state_beyond,
+        };
+        pager_impl(
+            ui,
+            target_page,
+            self.style,
+            self.id,
+            direction,
+            self.lifecycle.unwrap_or(&mut ()),
+            &scope,
+            add_contents,
+        )
+    }
+}
@@ -0,0 +1,425 @@
+use egui::{
+    popup::PopupCloseBehavior, AboveOrBelow, Align, Align2, Area, AreaState, Context, Frame, Id,
+    InnerResponse, Key, Layout, Order, Pos2, Response, ScrollArea, Sense, Shape, Stroke, TextStyle,
+    TextWrapMode, Ui, UiKind, Vec2, WidgetInfo, WidgetText, WidgetType,
+};
+use std::hash::Hash;
+
+use crate::{zoom_tstransform, TransitionStyle};
+
+/// Shows `area` zooming out of the `origin` and fading in as `visibility` goes from `0.0` to `1.0`.
+///
+/// The popup grows by [`TransitionStyle::amount`] while appearing.
+/// Unless `open`, the contents are disabled and the area doesn't take any input.
+fn show_zooming<R>(
+    ctx: &Context,
+    area: Area,
+    origin: Pos2,
+    open: bool,
+    visibility: f32,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    let area = if open { area } else { area.interactable(false) };
+    let response = area.fade_in(false).show(ctx, |ui| {
+        ui.multiply_opacity(visibility.clamp(0., 1.));
+        if !open {
+            ui.disable();
+        }
+        add_contents(ui)
+    });
+    if visibility != 1. {
+        ctx.transform_layer_shapes(
+            response.response.layer_id,
+            zoom_tstransform(
+                response.response.rect,
+                origin,
+                style.amount * (1. - visibility),
+            ),
+        );
+    }
+    response
+}
+
+/// Same as [`animated_popup_above_or_below_widget`] with [`AboveOrBelow::Below`].
+pub fn animated_popup_below_widget<R>(
+    parent_ui: &Ui,
+    popup_id: Id,
+    widget_response: &Response,
+    close_behavior: PopupCloseBehavior,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    animated_popup_above_or_below_widget(
+        parent_ui,
+        popup_id,
+        widget_response,
+        AboveOrBelow::Below,
+        close_behavior,
+        style,
+        add_contents,
+    )
+}
+
+/// Shows a popup above or below another widget, like [`egui::popup::popup_above_or_below_widget`],
+/// but the popup zooms out of the edge of the widget and fades in when opened, and does the reverse when closed.
+///
+/// The popup grows by [`TransitionStyle::amount`] while appearing and uses the [`easing`](TransitionStyle::easing)
+/// and [`duration`](TransitionStyle::duration) of the style, [`TransitionStyle::t_type`] is ignored.
+/// It's still shown (disabled) while closing.
+///
+/// Open the popup with [`egui::Memory::open_popup`] or [`egui::Memory::toggle_popup`].
+///
+/// Returns [`None`] if the popup is fully closed.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui::popup::PopupCloseBehavior;
+/// use egui_transition_animation::{animated_popup_below_widget, TransitionStyle};
+///
+/// let response = ui.button("Open popup");
+/// let popup_id = ui.make_persistent_id("popup");
+/// if response.clicked() {
+///     ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+/// }
+/// let style = TransitionStyle::vertical(ui);
+/// animated_popup_below_widget(ui, popup_id, &response, PopupCloseBehavior::CloseOnClickOutside, &style, |ui| {
+///     ui.label("Some more info");
+/// });
+/// # }
+/// ```
+pub fn animated_popup_above_or_below_widget<R>(
+    parent_ui: &Ui,
+    popup_id: Id,
+    widget_response: &Response,
+    above_or_below: AboveOrBelow,
+    close_behavior: PopupCloseBehavior,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    let ctx = parent_ui.ctx();
+    let open = ctx.memory(|memory| memory.is_popup_open(popup_id));
+    let visibility = ctx.animate_bool_with_time_and_easing(
+        popup_id.with("visibility"),
+        open,
+        style.duration,
+        style.easing,
+    );
+    if !open && visibility <= 0. {
+        return None;
+    }
+
+    let mut rect = widget_response.rect;
+    if let Some(to_global) = ctx.layer_transform_to_global(parent_ui.layer_id()) {
+        rect = to_global * rect;
+    }
+    let (pos, pivot, origin) = match above_or_below {
+        AboveOrBelow::Above => (rect.left_top(), Align2::LEFT_BOTTOM, rect.center_top()),
+        AboveOrBelow::Below => (rect.left_bottom(), Align2::LEFT_TOP, rect.center_bottom()),
+    };
+
+    let frame = Frame::popup(parent_ui.style());
+    let inner_width = (rect.width() - frame.total_margin().sum().x).max(0.);
+    let area = Area::new(popup_id)
+        .kind(UiKind::Popup)
+        .order(Order::Foreground)
+        .fixed_pos(pos)
+        .default_width(inner_width)
+        .pivot(pivot);
+    let response = show_zooming(ctx, area, origin, open, visibility, style, |ui| {
+        frame
+            .show(ui, |ui| {
+                ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                    ui.set_min_width(inner_width);
+                    add_contents(ui)
+                })
+                .inner
+            })
+            .inner
+    });
+
+    if open {
+        let should_close = match close_behavior {
+            PopupCloseBehavior::CloseOnClick => widget_response.clicked_elsewhere(),
+            PopupCloseBehavior::CloseOnClickOutside => {
+                widget_response.clicked_elsewhere() && response.response.clicked_elsewhere()
+            }
+            PopupCloseBehavior::IgnoreClicks => false,
+        };
+        if should_close || ctx.input(|input| input.key_pressed(Key::Escape)) {
+            ctx.memory_mut(|memory| memory.close_popup());
+        }
+    }
+    Some(response.inner)
+}
+
+/// Shows a tooltip under the widget (or above it if there isn't enough space) while it's hovered,
+/// like [`Response::on_hover_ui`], but the tooltip zooms out of the widget and fades in, and does the reverse
+/// when the widget stops being hovered.
+///
+/// The tooltip appears after the pointer rests on the widget for [`egui::style::Interaction::tooltip_delay`].
+/// The transition uses the style the same way as [`animated_popup_above_or_below_widget`].
+///
+/// Returns [`None`] if the tooltip is fully hidden.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{animated_tooltip, TransitionStyle};
+///
+/// let response = ui.button("Save");
+/// animated_tooltip(&response, &TransitionStyle::vertical(ui), |ui| {
+///     ui.label("Saves the file");
+/// });
+/// # }
+/// ```
+pub fn animated_tooltip<R>(
+    response: &Response,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    let ctx = &response.ctx;
+    let id = response.id.with("animated_tooltip");
+
+    let was_shown = ctx.data(|data| data.get_temp::<bool>(id)).unwrap_or(false);
+    let hovered = response.enabled() && response.hovered() && !response.is_pointer_button_down_on();
+    let shown = hovered
+        && (was_shown || {
+            let delay = ctx.style().interaction.tooltip_delay;
+            let resting = ctx.input(|input| input.pointer.time_since_last_movement());
+            if resting < delay {
+                ctx.request_repaint_after_secs(delay - resting);
+            }
+            resting >= delay
+        });
+    ctx.data_mut(|data| data.insert_temp(id, shown));
+
+    let visibility = ctx.animate_bool_with_time_and_easing(
+        id.with("visibility"),
+        shown,
+        style.duration,
+        style.easing,
+    );
+    if !shown && visibility <= 0. {
+        return None;
+    }
+
+    let mut rect = response.rect;
+    if let Some(to_global) = ctx.layer_transform_to_global(response.layer_id) {
+        rect = to_global * rect;
+    }
+    let expected_height = AreaState::load(ctx, id)
+        .and_then(|area| area.size)
+        .map_or(32., |size| size.y);
+    let (pos, pivot, origin) = if rect.bottom() + expected_height < ctx.screen_rect().bottom() {
+        (rect.left_bottom(), Align2::LEFT_TOP, rect.center_bottom())
+    } else {
+        (rect.left_top(), Align2::LEFT_BOTTOM, rect.center_top())
+    };
+
+    let area = Area::new(id)
+        .kind(UiKind::Popup)
+        .order(Order::Tooltip)
+        .fixed_pos(pos)
+        .pivot(pivot)
+        .default_width(ctx.style().spacing.tooltip_width)
+        .interactable(false);
+    let inner = show_zooming(ctx, area, origin, shown, visibility, style, |ui| {
+        ui.style_mut().interaction.selectable_labels = false;
+        Frame::popup(ui.style()).show(ui, add_contents).inner
+    })
+    .inner;
+    Some(inner)
+}
+
+/// Combo box whose dropdown zooms out of the button and fades in when opened,
+/// and animates closed before disappearing.
+///
+/// It works like a basic [`egui::ComboBox`], the dropdown is shown using [`animated_popup_above_or_below_widget`].
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::AnimatedComboBox;
+///
+/// let mut selected = 1; // Should be stored in your app's state
+///
+/// AnimatedComboBox::from_id_salt("size")
+///     .selected_text(format!("Size {selected}"))
+///     .show_ui(ui, |ui| {
+///         for size in 1..=3 {
+///             ui.selectable_value(&mut selected, size, format!("Size {size}"));
+///         }
+///     });
+/// # }
+/// ```
+pub struct AnimatedComboBox {
+    id_salt: Id,
+    selected_text: WidgetText,
+    width: Option<f32>,
+    height: Option<f32>,
+    style: Option<TransitionStyle>,
+}
+
+impl AnimatedComboBox {
+    pub fn from_id_salt(id_salt: impl Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            selected_text: WidgetText::default(),
+            width: None,
+            height: None,
+            style: None,
+        }
+    }
+
+    /// Text shown in the button. Defaults to no text.
+    pub fn selected_text(mut self, selected_text: impl Into<WidgetText>) -> Self {
+        self.selected_text = selected_text.into();
+        self
+    }
+
+    /// Minimal width of the button. Defaults to [`egui::style::Spacing::combo_width`].
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Maximal height of the dropdown, it scrolls if the contents are taller.
+    /// Defaults to [`egui::style::Spacing::combo_height`].
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::vertical`].
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Shows the button and, if it's open or closing, the dropdown.
+    /// The dropdown closes when anything is clicked.
+    ///
+    /// Returns `InnerResponse { inner: None }` if the dropdown is fully closed.
+    pub fn show_ui<R>(
+        self,
+        ui: &mut Ui,
+        menu_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let button_id = ui.make_persistent_id(self.id_salt);
+        let popup_id = button_id.with("popup");
+        let style = self.style.unwrap_or_else(|| TransitionStyle::vertical(ui));
+        let open = ui.memory(|memory| memory.is_popup_open(popup_id));
+
+        let popup_height = AreaState::load(ui.ctx(), popup_id)
+            .and_then(|area| area.size)
+            .map_or(100., |size| size.y);
+        let above_or_below =
+            if ui.next_widget_position().y + ui.spacing().interact_size.y + popup_height
+                < ui.ctx().screen_rect().bottom()
+            {
+                AboveOrBelow::Below
+            } else {
+                AboveOrBelow::Above
+            };
+
+        let response = button(
+            ui,
+            button_id,
+            self.selected_text,
+            self.width,
+            open,
+            above_or_below,
+        );
+        if response.clicked() {
+            ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+        }
+
+        let height = self.height.unwrap_or_else(|| ui.spacing().combo_height);
+        let inner = animated_popup_above_or_below_widget(
+            ui,
+            popup_id,
+            &response,
+            above_or_below,
+            PopupCloseBehavior::CloseOnClick,
+            &style,
+            |ui| {
+                ScrollArea::vertical()
+                    .max_height(height)
+                    .show(ui, |ui| {
+                        // The dropdown is often narrow, so don't wrap the options early
+                        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                        menu_contents(ui)
+                    })
+                    .inner
+            },
+        );
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Allocates the button of [`AnimatedComboBox`] with the selected text and an arrow pointing towards the dropdown
+fn button(
+    ui: &mut Ui,
+    id: Id,
+    selected_text: WidgetText,
+    width: Option<f32>,
+    open: bool,
+    above_or_below: AboveOrBelow,
+) -> Response {
+    let padding = ui.spacing().button_padding;
+    let icon_spacing = ui.spacing().icon_spacing;
+    let icon_size = Vec2::splat(ui.spacing().icon_width);
+    let galley = selected_text.into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    );
+    let size = Vec2::new(
+        (galley.size().x + icon_spacing + icon_size.x + 2. * padding.x)
+            .max(width.unwrap_or_else(|| ui.spacing().combo_width)),
+        (galley.size().y.max(icon_size.y) + 2. * padding.y).max(ui.spacing().interact_size.y),
+    );
+
+    let (_, rect) = ui.allocate_space(size);
+    let response = ui.interact(rect, id, Sense::click());
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, ui.is_enabled(), ""));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = if open {
+            &ui.visuals().widgets.open
+        } else {
+            ui.style().interact(&response)
+        };
+        ui.painter().rect(
+            rect.expand(visuals.expansion),
+            visuals.rounding,
+            visuals.weak_bg_fill,
+            visuals.bg_stroke,
+        );
+
+        let inner = rect.shrink2(padding);
+        let icon_rect = Align2::RIGHT_CENTER.align_size_within_rect(icon_size, inner);
+        let arrow = egui::Rect::from_center_size(
+            icon_rect.center(),
+            Vec2::new(icon_rect.width() * 0.7, icon_rect.height() * 0.45),
+        );
+        let points = match above_or_below {
+            AboveOrBelow::Above => vec![
+                arrow.left_bottom(),
+                arrow.right_bottom(),
+                arrow.center_top(),
+            ],
+            AboveOrBelow::Below => vec![arrow.left_top(), arrow.right_top(), arrow.center_bottom()],
+        };
+        ui.painter().add(Shape::convex_polygon(
+            points,
+            visuals.fg_stroke.color,
+            Stroke::NONE,
+        ));
+
+        let text_rect = Align2::LEFT_CENTER.align_size_within_rect(galley.size(), inner);
+        ui.painter()
+            .galley(text_rect.min, galley, visuals.text_color());
+    }
+    response
+}
@@ -0,0 +1,100 @@
+use egui::{emath::TSTransform, Direction, Id, InnerResponse, Rect, Sense, Ui, UiBuilder, Vec2};
+
+use crate::TransitionStyle;
+
+/// How a partially visible ui is shown while appearing or disappearing
+#[derive(Clone, Copy)]
+pub(crate) struct Reveal {
+    /// Offset of the ui when it's fully hidden
+    pub(crate) slide: Vec2,
+    pub(crate) fade: bool,
+    /// Shrink the space taken by the ui along the main direction of the layout
+    pub(crate) collapse: bool,
+}
+
+impl Reveal {
+    /// Slides along the axis of the style's [`TransitionType`](crate::TransitionType) by its `amount`
+    pub(crate) fn new(style: &TransitionStyle, slide: bool, fade: bool, collapse: bool) -> Self {
+        let mut direction = style.t_type.axis();
+        if style.text_direction.is_rtl() {
+            direction.x = -direction.x;
+        }
+        Self {
+            slide: if slide {
+                direction * style.amount
+            } else {
+                Vec2::ZERO
+            },
+            fade,
+            collapse,
+        }
+    }
+
+    /// Shows `add_contents` `visibility` visible, where `0.0` is fully hidden and `1.0` fully visible.
+    ///
+    /// `id` is used to remember the full size of the contents from the previous frame.
+    pub(crate) fn show<R>(
+        &self,
+        ui: &mut Ui,
+        id: Id,
+        visibility: f32,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let main_dir = ui.layout().main_dir();
+        let full_size: Option<Vec2> = ui.ctx().data(|data| data.get_temp(id));
+
+        let mut child = ui.new_child(
+            UiBuilder::new()
+                .id_salt(id)
+                .max_rect(ui.available_rect_before_wrap())
+                .layout(*ui.layout()),
+        );
+        let collapsing = self.collapse && visibility < 1.;
+        if collapsing {
+            let shown = shrink_along(
+                child.max_rect(),
+                main_dir,
+                full_size.unwrap_or_default() * visibility,
+            );
+            child.set_clip_rect(child.clip_rect().intersect(shown));
+        }
+        if self.fade {
+            child.multiply_opacity(visibility.clamp(0., 1.));
+        }
+        let inner = child
+            .with_visual_transform(
+                TSTransform::from_translation(self.slide * (1. - visibility)),
+                add_contents,
+            )
+            .inner;
+
+        let full_rect = child.min_rect();
+        ui.ctx()
+            .data_mut(|data| data.insert_temp(id, full_rect.size()));
+
+        let rect = if collapsing {
+            shrink_along(full_rect, main_dir, full_rect.size() * visibility.max(0.))
+        } else {
+            full_rect
+        };
+        let response = if rect.width() > 0. && rect.height() > 0. {
+            ui.allocate_rect(rect, Sense::hover())
+        } else {
+            // Don't add item spacing for fully collapsed contents
+            ui.interact(Rect::NOTHING, id, Sense::hover())
+        };
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Shrinks `rect` along the `main_dir` of a layout to `size`, keeping the edge where the layout starts
+fn shrink_along(rect: Rect, main_dir: Direction, size: Vec2) -> Rect {
+    let mut shrunk = rect;
+    match main_dir {
+        Direction::LeftToRight => shrunk.max.x = rect.min.x + size.x,
+        Direction::RightToLeft => shrunk.min.x = rect.max.x - size.x,
+        Direction::TopDown => shrunk.max.y = rect.min.y + size.y,
+        Direction::BottomUp => shrunk.min.y = rect.max.y - size.y,
+    }
+    shrunk
+}
@@ -0,0 +1,281 @@
+use egui::{Id, Ui};
+use std::{hash::Hash, str::FromStr};
+
+use crate::{animated_pager_with_direction, history::NavigationHistory, PagerRet, TransitionStyle};
+
+/// Parameters captured from a path by a [`Router`] route pattern.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteParams {
+    params: Vec<(String, String)>,
+}
+
+impl RouteParams {
+    /// Value of the parameter named `name` (without the leading `:`).
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Value of the parameter named `name` parsed as `T`.
+    /// Returns [`None`] if there's no such parameter or if it can't be parsed.
+    pub fn parse<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.get(name)?.parse().ok()
+    }
+}
+
+enum Segment {
+    Literal(String),
+    Param(String),
+}
+
+type MakePage<'a, Page> = Box<dyn Fn(&RouteParams) -> Option<Page> + 'a>;
+
+struct Route<'a, Page> {
+    segments: Vec<Segment>,
+    make_page: MakePage<'a, Page>,
+}
+
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+impl<'a, Page> Route<'a, Page> {
+    fn new(pattern: &str, make_page: MakePage<'a, Page>) -> Self {
+        let segments = split_path(pattern)
+            .map(|segment| match segment.strip_prefix(':') {
+                Some(name) => Segment::Param(name.to_owned()),
+                None => Segment::Literal(segment.to_owned()),
+            })
+            .collect();
+        Self {
+            segments,
+            make_page,
+        }
+    }
+
+    fn matches(&self, path: &str) -> Option<RouteParams> {
+        let mut params = RouteParams::default();
+        let mut segments = self.segments.iter();
+        for part in split_path(path) {
+            match segments.next()? {
+                Segment::Literal(literal) if literal == part => {}
+                Segment::Literal(_) => return None,
+                Segment::Param(name) => params.params.push((name.clone(), part.to_owned())),
+            }
+        }
+        // The path must not be shorter than the pattern
+        segments.next().is_none().then_some(params)
+    }
+}
+
+/// Maps string paths like `/users/42/settings` to typed pages and shows them in an animated pager.
+///
+/// The router keeps back/forward history of visited pages. Navigating to a new page and going forward
+/// in history is animated _forward_, going back in history is animated _backward_.
+///
+/// Patterns consist of literal segments and parameters starting with `:`, eg. `/users/:id/settings`.
+/// Trailing and repeated slashes are ignored.
+///
+/// ```
+/// use egui_transition_animation::Router;
+///
+/// #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+/// enum Page {
+///     Home,
+///     UserSettings { user: u32 },
+/// }
+///
+/// let mut router = Router::new(Page::Home)
+///     .route("/", |_| Some(Page::Home))
+///     .route("/users/:id/settings", |params| {
+///         Some(Page::UserSettings { user: params.parse("id")? })
+///     });
+///
+/// assert!(router.navigate("/users/42/settings"));
+/// assert_eq!(router.current(), &Page::UserSettings { user: 42 });
+/// assert!(!router.navigate("/users/nobody/settings"));
+///
+/// assert!(router.back());
+/// assert_eq!(router.current(), &Page::Home);
+/// ```
+pub struct Router<'a, Page> {
+    routes: Vec<Route<'a, Page>>,
+    history: NavigationHistory<Page>,
+    /// Whether the last navigation went forward
+    forward: bool,
+}
+
+impl<'a, Page: Sync + Send + Clone + 'static + Eq + Hash> Router<'a, Page> {
+    /// Creates a router showing `initial` page, with no routes.
+    pub fn new(initial: Page) -> Self {
+        Self {
+            routes: Vec::new(),
+            history: NavigationHistory::new(initial, 100),
+            forward: true,
+        }
+    }
+
+    /// Registers a route. `make_page` creates the page from the parameters captured from the path
+    /// and may return [`None`] to reject the path, eg. when a parameter can't be parsed.
+    ///
+    /// Routes are tried in the order they were registered.
+    pub fn route(
+        mut self,
+        pattern: &str,
+        make_page: impl Fn(&RouteParams) -> Option<Page> + 'a,
+    ) -> Self {
+        self.routes.push(Route::new(pattern, Box::new(make_page)));
+        self
+    }
+
+    /// Maximal number of pages kept in the history. Defaults to 100.
+    pub fn max_history(mut self, max_history: usize) -> Self {
+        self.history.set_max_len(max_history);
+        self
+    }
+
+    /// Returns the page the `path` maps to, without navigating to it.
+    pub fn resolve(&self, path: &str) -> Option<Page> {
+        self.routes
+            .iter()
+            .find_map(|route| (route.make_page)(&route.matches(path)?))
+    }
+
+    /// Navigates to the page the `path` maps to.
+    ///
+    /// Returns `false` and doesn't navigate anywhere if no route matches the path.
+    pub fn navigate(&mut self, path: &str) -> bool {
+        match self.resolve(path) {
+            Some(page) => {
+                self.push(page);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Navigates to `page`, dropping the forward history.
+    ///
+    /// Does nothing if `page` is already the current page.
+    pub fn push(&mut self, page: Page) {
+        if *self.current() == page {
+            return;
+        }
+        self.history.push(page);
+        self.forward = true;
+    }
+
+    /// The page the router navigated to last.
+    pub fn current(&self) -> &Page {
+        self.history.current()
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        self.history.can_go_back()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        self.history.can_go_forward()
+    }
+
+    /// Goes back in history. Returns `false` if there's nowhere to go back.
+    pub fn back(&mut self) -> bool {
+        if !self.history.back() {
+            return false;
+        }
+        self.forward = false;
+        true
+    }
+
+    /// Goes forward in history. Returns `false` if there's nowhere to go forward.
+    pub fn forward(&mut self) -> bool {
+        if !self.history.forward() {
+            return false;
+        }
+        self.forward = true;
+        true
+    }
+
+    /// Shows the current page in an animated pager.
+    ///
+    /// # Parameters
+    ///  - `add_contents`: Function that shows given `Page` in a [`Ui`].
+    ///    The `Page` argument of this function may differ from the [current](Router::current) page if an animation is running.
+    ///    To navigate from inside of it, remember the path and call [`navigate`](Router::navigate) after `show` returns.
+    pub fn show<Ret>(
+        &self,
+        ui: &mut Ui,
+        style: &TransitionStyle,
+        id: Id,
+        add_contents: impl FnOnce(&mut Ui, Page) -> Ret,
+    ) -> PagerRet<Page, Ret> {
+        animated_pager_with_direction(
+            ui,
+            self.current().clone(),
+            style,
+            id,
+            |_, _| self.forward,
+            add_contents,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn route(pattern: &str) -> Route<'static, ()> {
+        Route::new(pattern, Box::new(|_| Some(())))
+    }
+
+    fn params(route: &Route<'_, ()>, path: &str) -> Option<Vec<(String, String)>> {
+        route.matches(path).map(|params| params.params)
+    }
+
+    #[test]
+    fn root_matches_only_empty_paths() {
+        let root = route("/");
+        assert_eq!(params(&root, "/"), Some(vec![]));
+        assert_eq!(params(&root, ""), Some(vec![]));
+        assert_eq!(params(&root, "//"), Some(vec![]));
+        assert_eq!(params(&root, "/users"), None);
+    }
+
+    #[test]
+    fn literal_segments_must_be_equal() {
+        let users = route("/users/list");
+        assert!(users.matches("/users/list").is_some());
+        assert!(users.matches("/users/other").is_none());
+        assert!(users.matches("/Users/list").is_none());
+    }
+
+    #[test]
+    fn trailing_and_repeated_slashes_are_ignored() {
+        let settings = route("/users/:id/settings");
+        let expected = Some(vec![("id".to_owned(), "42".to_owned())]);
+        assert_eq!(params(&settings, "/users/42/settings/"), expected);
+        assert_eq!(params(&settings, "//users///42/settings"), expected);
+        assert_eq!(params(&settings, "users/42/settings"), expected);
+    }
+
+    #[test]
+    fn path_length_must_match_pattern() {
+        let settings = route("/users/:id/settings");
+        assert!(settings.matches("/users/42").is_none());
+        assert!(settings.matches("/users/42/settings/extra").is_none());
+        assert!(settings.matches("/").is_none());
+    }
+
+    #[test]
+    fn parameters_are_captured_in_order() {
+        let post = route("/users/:user/posts/:post");
+        let params = post.matches("/users/7/posts/hello").unwrap();
+        assert_eq!(params.get("user"), Some("7"));
+        assert_eq!(params.get("post"), Some("hello"));
+        assert_eq!(params.parse::<u32>("user"), Some(7));
+        assert_eq!(params.parse::<u32>("post"), None);
+        assert_eq!(params.get("missing"), None);
+    }
+}
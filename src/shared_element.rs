@@ -0,0 +1,125 @@
+use egui::{emath::TSTransform, Id, InnerResponse, LayerId, Rect, Ui, UiBuilder};
+use std::hash::Hash;
+
+use crate::{transition_progress, TransitionStyle};
+
+#[derive(Clone, Copy, Default)]
+struct SharedElementState {
+    /// Rect of the element on the page being left and the pass it was recorded in
+    departure: Option<(Rect, u64)>,
+    /// Rect the element is flying from during the second half of a transition
+    flight_from: Option<Rect>,
+}
+
+/// Shows a "hero" element that flies from its place on the old page to its place on the new page
+/// during a transition of the pager with given `pager_id`.
+///
+/// Tag the corresponding widgets on both pages with the same `key`, eg. a photo thumbnail in a grid
+/// and the photo on its detail page. Keys must be unique within a page.
+/// When the pager switches the pages in the middle of the transition, the element on the new page is painted
+/// moving and scaling from the rect of the element on the old page to its own rect, while the rest of the page
+/// does its normal transition. Elements without a counterpart on the old page are shown normally.
+///
+/// The `style` should be the same as the pager's.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{prelude::*, shared_element};
+///
+/// #[derive(Clone, PartialEq, Eq, PartialOrd, Hash)]
+/// enum Page {
+///     Gallery,
+///     Photo(usize),
+/// }
+///
+/// let mut page = Page::Gallery; // Should be stored in your app's state
+/// let pager_id = egui::Id::new("pager");
+/// let style = TransitionStyle::horizontal(ui);
+///
+/// animated_pager(ui, page.clone(), &style, pager_id, |ui, shown| match shown {
+///     Page::Gallery => {
+///         for photo in 0..3 {
+///             let thumbnail = shared_element(ui, pager_id, &style, photo, |ui| {
+///                 ui.add_sized([64., 64.], egui::Button::new(format!("Photo {photo}")))
+///             });
+///             if thumbnail.inner.clicked() {
+///                 page = Page::Photo(photo);
+///             }
+///         }
+///     }
+///     Page::Photo(photo) => {
+///         shared_element(ui, pager_id, &style, photo, |ui| {
+///             ui.add_sized([256., 256.], egui::Button::new(format!("Photo {photo}")))
+///         });
+///     }
+/// });
+/// # }
+/// ```
+pub fn shared_element<R>(
+    ui: &mut Ui,
+    pager_id: Id,
+    style: &TransitionStyle,
+    key: impl Hash,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    let id = pager_id.with("shared_element").with(key);
+    let layer_id = LayerId::new(ui.layer_id().order, id);
+    let pass = ui.ctx().cumulative_pass_nr();
+    let mut state = ui
+        .ctx()
+        .data(|data| data.get_temp::<SharedElementState>(id))
+        .unwrap_or_default();
+
+    // The same condition as in `page_transition_towards`
+    let second_half = transition_progress(ui.ctx(), pager_id)
+        .map(|progress| (style.easing)(progress))
+        .filter(|anim_state| *anim_state > 0.5);
+
+    let response = match second_half {
+        Some(anim_state) => {
+            if state.flight_from.is_none() {
+                // Only fly from a rect recorded on the old page right before it was replaced,
+                // allowing for a discarded pass in between
+                state.flight_from = state
+                    .departure
+                    .filter(|(_, departure_pass)| pass.saturating_sub(*departure_pass) <= 2)
+                    .map(|(rect, _)| rect);
+            }
+            match state.flight_from {
+                Some(from) => {
+                    ui.ctx().set_sublayer(ui.layer_id(), layer_id);
+                    let response = ui.scope_builder(UiBuilder::new().layer_id(layer_id), |ui| {
+                        ui.set_clip_rect(Rect::EVERYTHING);
+                        add_contents(ui)
+                    });
+                    let to = response.response.rect;
+                    let t = (anim_state - 0.5) * 2.;
+                    let rect =
+                        Rect::from_min_max(from.min.lerp(to.min, t), from.max.lerp(to.max, t));
+                    let scaling = if to.width() > 0. {
+                        rect.width() / to.width()
+                    } else {
+                        1.
+                    };
+                    ui.ctx().set_transform_layer(
+                        layer_id,
+                        TSTransform::new(rect.min.to_vec2() - to.min.to_vec2() * scaling, scaling),
+                    );
+                    response
+                }
+                None => ui.scope(add_contents),
+            }
+        }
+        None => {
+            state.flight_from = None;
+            ui.ctx()
+                .set_transform_layer(layer_id, TSTransform::IDENTITY);
+            let response = ui.scope(add_contents);
+            state.departure = Some((response.response.rect, pass));
+            response
+        }
+    };
+
+    ui.ctx().data_mut(|data| data.insert_temp(id, state));
+    response
+}
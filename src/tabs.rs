@@ -0,0 +1,232 @@
+use egui::{Id, Rect, ScrollArea, Stroke, Ui, WidgetText};
+use std::hash::Hash;
+
+use crate::{
+    animated_pager_with_direction, PagerRet, PagerTransition, TransitionStyle, TransitionType,
+};
+
+/// Tab bar with a pager showing the selected tab.
+///
+/// The highlight of the selected tab slides between the tabs in sync with the page transition.
+/// The orientation of the tab bar matches [`TransitionStyle::t_type`] - tabs are laid out horizontally
+/// above the page for [`TransitionType::HorizontalMove`] and vertically left of the page for [`TransitionType::VerticalMove`].
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::AnimatedTabs;
+///
+/// // Should be stored in your app's state
+/// let mut tabs = vec!["Inbox".to_owned(), "Drafts".to_owned(), "Sent".to_owned()];
+/// let mut selected = tabs[0].clone();
+///
+/// AnimatedTabs::new("mail_tabs").closable(true).show(
+///     ui,
+///     &mut tabs,
+///     &mut selected,
+///     |tab| tab.as_str().into(),
+///     |ui, tab| ui.label(format!("Contents of {tab}")),
+/// );
+/// # }
+/// ```
+pub struct AnimatedTabs {
+    id: Id,
+    style: Option<TransitionStyle>,
+    closable: bool,
+}
+
+impl AnimatedTabs {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            style: None,
+            closable: false,
+        }
+    }
+
+    /// Style of the transition, also determines the orientation of the tab bar.
+    /// Defaults to [`TransitionStyle::horizontal`].
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Whether to show a close button on each tab. Defaults to `false`.
+    ///
+    /// Closing a tab removes it from the `tabs` passed to [`show`](AnimatedTabs::show).
+    /// If the selected tab is closed, the next one (or the previous one if it was the last) gets selected.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Id of the pager showing the selected tab, eg. for use with [`PageIndicator`](crate::PageIndicator).
+    pub fn pager_id(&self) -> Id {
+        self.id.with("pager")
+    }
+
+    /// Shows the tab bar and the selected tab. Returns [`None`] if `tabs` is empty.
+    ///
+    /// # Parameters
+    ///  - `tabs`: All tabs, in the order they should be shown. The direction of the animation is determined by this order.
+    ///  - `selected`: The selected tab. Changed when the user clicks on other tab or closes the selected one.
+    ///  - `title`: Function returning the text shown in the tab header.
+    ///  - `add_contents`: Function that shows given tab in a [`Ui`].
+    ///    The tab argument of this function may differ from `selected` if an animation is running.
+    pub fn show<Tab: Sync + Send + Clone + 'static + Eq + Hash, Ret>(
+        mut self,
+        ui: &mut Ui,
+        tabs: &mut Vec<Tab>,
+        selected: &mut Tab,
+        title: impl FnMut(&Tab) -> WidgetText,
+        add_contents: impl FnOnce(&mut Ui, Tab) -> Ret,
+    ) -> Option<PagerRet<Tab, Ret>> {
+        let style = self
+            .style
+            .take()
+            .unwrap_or_else(|| TransitionStyle::horizontal(ui));
+        let pager_id = self.pager_id();
+        let horizontal = style.t_type == TransitionType::HorizontalMove;
+
+        if horizontal {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| self.tab_bar(ui, tabs, selected, title, &style, horizontal));
+                ui.separator();
+                show_pager(ui, tabs, selected, &style, pager_id, add_contents)
+            })
+            .inner
+        } else {
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| self.tab_bar(ui, tabs, selected, title, &style, horizontal));
+                ui.separator();
+                ui.vertical(|ui| show_pager(ui, tabs, selected, &style, pager_id, add_contents))
+                    .inner
+            })
+            .inner
+        }
+    }
+
+    fn tab_bar<Tab: Sync + Send + Clone + 'static + Eq>(
+        &self,
+        ui: &mut Ui,
+        tabs: &mut Vec<Tab>,
+        selected: &mut Tab,
+        mut title: impl FnMut(&Tab) -> WidgetText,
+        style: &TransitionStyle,
+        horizontal: bool,
+    ) {
+        let scroll_area = if horizontal {
+            ScrollArea::horizontal()
+        } else {
+            ScrollArea::vertical()
+        };
+        scroll_area.id_salt(self.id.with("scroll")).show(ui, |ui| {
+            let mut rects = Vec::with_capacity(tabs.len());
+            let mut closed = None;
+            for (index, tab) in tabs.iter().enumerate() {
+                let is_selected = tab == selected;
+                let mut text = title(tab);
+                if is_selected {
+                    text = text.strong();
+                }
+
+                let header = ui.horizontal(|ui| {
+                    let response = ui.add(egui::Button::new(text).frame(false));
+                    if response.clicked() && !is_selected {
+                        *selected = tab.clone();
+                        response.scroll_to_me(None);
+                    }
+                    if self.closable && ui.small_button("🗙").clicked() {
+                        closed = Some(index);
+                    }
+                });
+                rects.push(header.response.rect);
+            }
+
+            paint_highlight(
+                ui,
+                tabs,
+                selected,
+                &rects,
+                self.pager_id(),
+                style,
+                horizontal,
+            );
+
+            if let Some(closed) = closed {
+                let removed = tabs.remove(closed);
+                if removed == *selected {
+                    if let Some(neighbour) = tabs.get(closed).or(tabs.last()) {
+                        *selected = neighbour.clone();
+                    }
+                }
+            }
+        });
+    }
+}
+
+fn show_pager<Tab: Sync + Send + Clone + 'static + Eq + Hash, Ret>(
+    ui: &mut Ui,
+    tabs: &[Tab],
+    selected: &Tab,
+    style: &TransitionStyle,
+    pager_id: Id,
+    add_contents: impl FnOnce(&mut Ui, Tab) -> Ret,
+) -> Option<PagerRet<Tab, Ret>> {
+    if tabs.is_empty() {
+        return None;
+    }
+    let position = |tab: &Tab| tabs.iter().position(|other| other == tab);
+    Some(animated_pager_with_direction(
+        ui,
+        selected.clone(),
+        style,
+        pager_id,
+        // Closed tabs have no position, so they are treated as being before all the other tabs
+        |original_tab, new_tab| position(original_tab) < position(new_tab),
+        add_contents,
+    ))
+}
+
+/// Paints the line marking the selected tab, moving it between the tabs during the transition.
+fn paint_highlight<Tab: Sync + Send + Clone + 'static + Eq>(
+    ui: &Ui,
+    tabs: &[Tab],
+    selected: &Tab,
+    rects: &[Rect],
+    pager_id: Id,
+    style: &TransitionStyle,
+    horizontal: bool,
+) {
+    let rect_of = |tab: &Tab| {
+        tabs.iter()
+            .position(|other| other == tab)
+            .and_then(|index| rects.get(index).copied())
+    };
+
+    let transition = PagerTransition::<Tab>::load(ui.ctx(), pager_id);
+    let rect = match transition.as_ref().map(|transition| {
+        (
+            rect_of(&transition.from),
+            rect_of(&transition.to),
+            transition.progress,
+        )
+    }) {
+        Some((Some(from), Some(to), progress)) => {
+            let t = (style.easing)(progress);
+            Rect::from_min_max(from.min.lerp(to.min, t), from.max.lerp(to.max, t))
+        }
+        Some((None, Some(to), _)) => to,
+        _ => match rect_of(selected) {
+            Some(rect) => rect,
+            None => return,
+        },
+    };
+
+    let stroke = Stroke::new(2.0, ui.visuals().selection.bg_fill);
+    if horizontal {
+        ui.painter().hline(rect.x_range(), rect.bottom(), stroke);
+    } else {
+        ui.painter()
+            .vline(rect.left() - 2.0, rect.y_range(), stroke);
+    }
+}
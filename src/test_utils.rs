@@ -0,0 +1,93 @@
+//! Helpers for tests driving pagers through whole transitions
+
+use egui::{CentralPanel, Context, Id, RawInput};
+
+use crate::{AnimatedPager, PageLifecycle, TransitionStyle, TransitionType};
+
+/// Duration of the transitions in seconds
+const DURATION: f32 = 1.;
+
+pub(crate) fn pager_id() -> Id {
+    Id::new("pager")
+}
+
+/// Runs a frame at [`time`](egui::InputState::time) showing a pager with `target_page`
+fn show_pager(
+    ctx: &Context,
+    time: f64,
+    target_page: char,
+    lifecycle: &mut impl PageLifecycle<char>,
+) {
+    let input = RawInput {
+        time: Some(time),
+        ..Default::default()
+    };
+    let mut lifecycle = Some(lifecycle);
+    let _ = ctx.run(input, |ctx| {
+        let Some(lifecycle) = lifecycle.take() else {
+            return;
+        };
+        let style = TransitionStyle {
+            duration: DURATION,
+            ..TransitionStyle::for_context(ctx, TransitionType::HorizontalMove)
+        };
+        CentralPanel::default().show(ctx, |ui| {
+            AnimatedPager::new(pager_id(), &style)
+                .lifecycle(lifecycle)
+                .show(ui, target_page, |ui, page| ui.label(page.to_string()));
+        });
+    });
+}
+
+/// Transitions of a pager starting on page `A`, which is told to show page `B` at time `1.0`.
+/// The transition takes one second.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Scenario {
+    /// Shows `B`, rendering frames in both halves of the transition
+    Completed,
+    /// Goes back to `A` in the first half of the transition
+    Cancelled,
+    /// Goes to `C` in the second half of the transition, after `B` has started to appear
+    Retargeted,
+    /// Shows `B` without rendering any frame between the start and the end of the transition
+    Skipped,
+}
+
+impl Scenario {
+    pub(crate) const ALL: [Self; 4] = [
+        Self::Completed,
+        Self::Cancelled,
+        Self::Retargeted,
+        Self::Skipped,
+    ];
+
+    /// Frames of the scenario as `(time, target page)`, the transition finishes in the last one
+    fn frames(self) -> &'static [(f64, char)] {
+        match self {
+            Self::Completed => &[(0., 'A'), (1., 'B'), (1.25, 'B'), (1.75, 'B'), (2.5, 'B')],
+            Self::Cancelled => &[(0., 'A'), (1., 'B'), (1.25, 'A'), (2.5, 'A')],
+            Self::Retargeted => &[(0., 'A'), (1., 'B'), (1.75, 'B'), (1.8, 'C'), (2.5, 'C')],
+            Self::Skipped => &[(0., 'A'), (1., 'B'), (2.5, 'B')],
+        }
+    }
+
+    /// Runs the frames of the scenario
+    pub(crate) fn run(self, ctx: &Context, lifecycle: &mut impl PageLifecycle<char>) {
+        self.run_between(ctx, 0., f64::INFINITY, lifecycle);
+    }
+
+    /// Runs the frames of the scenario with time in `from..to`
+    pub(crate) fn run_between(
+        self,
+        ctx: &Context,
+        from: f64,
+        to: f64,
+        lifecycle: &mut impl PageLifecycle<char>,
+    ) {
+        for &(time, target_page) in self.frames() {
+            if (from..to).contains(&time) {
+                show_pager(ctx, time, target_page, lifecycle);
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use egui::{Context, Direction, Id, Ui};
+
+/// Direction of the text (and thus of the whole layout), see [`TransitionStyle::text_direction`](crate::TransitionStyle::text_direction).
+///
+/// In right-to-left layouts (eg. Arabic or Hebrew), going forward should slide the content
+/// the opposite way, so horizontal transitions are mirrored.
+///
+/// Set it once for the whole app using [`TextDirection::set_for_context`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum TextDirection {
+    /// Eg. English, forward transitions slide the content to the left
+    #[default]
+    LeftToRight,
+    /// Eg. Arabic or Hebrew, forward transitions slide the content to the right
+    RightToLeft,
+}
+
+impl TextDirection {
+    fn memory_id() -> Id {
+        Id::new("egui_transition_animation_text_direction")
+    }
+
+    /// Text direction of the given [`Ui`], which is the one set by [`TextDirection::set_for_context`],
+    /// or left-to-right.
+    pub fn from_ui(ui: &Ui) -> Self {
+        Self::for_context(ui.ctx()).unwrap_or_default()
+    }
+
+    /// Text direction inferred from the main direction of the ui's [layout](egui::Layout),
+    /// unless one is [set for the context](TextDirection::set_for_context).
+    ///
+    /// Only use it in uis whose layout follows the text direction. A right-to-left layout
+    /// is also used eg. to align toolbar buttons to the right, which doesn't make the text right-to-left.
+    pub fn from_layout(ui: &Ui) -> Self {
+        Self::for_context(ui.ctx()).unwrap_or(match ui.layout().main_dir() {
+            Direction::RightToLeft => Self::RightToLeft,
+            _ => Self::LeftToRight,
+        })
+    }
+
+    /// Text direction set for the whole [`Context`] by [`TextDirection::set_for_context`].
+    pub fn for_context(ctx: &Context) -> Option<Self> {
+        ctx.data(|data| data.get_temp(Self::memory_id()))
+    }
+
+    /// Sets text direction used by all transitions in the [`Context`].
+    ///
+    /// Pass [`None`] to go back to left-to-right, or to the direction inferred by [`TextDirection::from_layout`].
+    pub fn set_for_context(ctx: &Context, direction: Option<Self>) {
+        ctx.data_mut(|data| match direction {
+            Some(direction) => data.insert_temp(Self::memory_id(), direction),
+            None => data.remove::<Self>(Self::memory_id()),
+        });
+    }
+
+    /// Whether horizontal transitions should be mirrored.
+    pub fn is_rtl(self) -> bool {
+        self == Self::RightToLeft
+    }
+}
@@ -0,0 +1,262 @@
+use egui::{Align, Align2, Area, Context, Frame, Id, Layout, Order, Vec2, WidgetText};
+use std::hash::{Hash, Hasher};
+
+use crate::{AnimatedList, TransitionStyle, TransitionType};
+
+/// A notification shown by [`Toasts`]
+#[derive(Clone)]
+struct Toast {
+    id: u64,
+    text: WidgetText,
+    /// For how long the toast has been shown, excluding the time it was hovered.
+    /// [`None`] until the toast is shown for the first time.
+    age: Option<f64>,
+}
+
+// Toasts are identified by their id only, so `AnimatedList` keeps recognizing them as they age
+impl PartialEq for Toast {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Toast {}
+
+impl Hash for Toast {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+#[derive(Clone, Default)]
+struct ToastQueue {
+    toasts: Vec<Toast>,
+    next_id: u64,
+    /// [Time](egui::InputState::time) of the last frame the toasts were shown
+    last_time: Option<f64>,
+    /// Whether the stack was hovered in the last frame
+    hovered: bool,
+}
+
+/// Stack of toast notifications in a corner of the screen.
+///
+/// New toasts slide in, pushing the existing ones away from the corner,
+/// and expired toasts fade and slide out, see [`AnimatedList`].
+/// The countdown of all toasts pauses while the stack is hovered.
+///
+/// All timing is based on [`egui::InputState::time`], so the toasts behave deterministically
+/// when the time is driven by tests.
+///
+/// ```
+/// # fn show(ctx: &egui::Context, ui: &mut egui::Ui) {
+/// use egui_transition_animation::Toasts;
+///
+/// if ui.button("Save").clicked() {
+///     Toasts::new("toasts").add(ctx, "Saved");
+/// }
+///
+/// // Every frame
+/// Toasts::new("toasts").show(ctx);
+/// # }
+/// ```
+pub struct Toasts {
+    id: Id,
+    anchor: Align2,
+    duration: f64,
+    style: Option<TransitionStyle>,
+}
+
+impl Toasts {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            anchor: Align2::RIGHT_BOTTOM,
+            duration: 4.,
+            style: None,
+        }
+    }
+
+    /// Corner of the screen the toasts are stacked in. Defaults to [`Align2::RIGHT_BOTTOM`].
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// For how many seconds each toast is shown. Defaults to `4.0`.
+    pub fn duration(mut self, seconds: f64) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::for_context`] with [`TransitionType::HorizontalMove`],
+    /// so the toasts slide in from the side of the screen.
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Adds a toast to the stack. It appears when the stack is [shown](Toasts::show) next time.
+    pub fn add(&self, ctx: &Context, text: impl Into<WidgetText>) {
+        ctx.data_mut(|data| {
+            let queue = data.get_temp_mut_or_default::<ToastQueue>(self.id);
+            queue.toasts.push(Toast {
+                id: queue.next_id,
+                text: text.into(),
+                age: None,
+            });
+            queue.next_id += 1;
+        });
+        ctx.request_repaint();
+    }
+
+    /// Shows the toasts. Call it every frame, even if there are no toasts.
+    pub fn show(self, ctx: &Context) {
+        let time = ctx.input(|input| input.time);
+        let mut queue = ctx
+            .data(|data| data.get_temp::<ToastQueue>(self.id))
+            .unwrap_or_default();
+
+        let elapsed = queue.last_time.map_or(0., |last_time| time - last_time);
+        queue.last_time = Some(time);
+        for toast in &mut queue.toasts {
+            toast.age = Some(match toast.age {
+                // The time before the toast is shown doesn't count, eg. when no frames were running
+                None => 0.,
+                Some(age) if queue.hovered => age,
+                Some(age) => age + elapsed,
+            });
+        }
+        queue
+            .toasts
+            .retain(|toast| toast.age.unwrap_or_default() < self.duration);
+
+        let mut style = self
+            .style
+            .unwrap_or_else(|| TransitionStyle::for_context(ctx, TransitionType::HorizontalMove));
+        // Slide in from the nearest edge of the screen
+        if (style.t_type == TransitionType::HorizontalMove && self.anchor.x() == Align::Min)
+            || (style.t_type == TransitionType::VerticalMove && self.anchor.y() == Align::Min)
+        {
+            style.amount = -style.amount;
+        }
+
+        let from_bottom = self.anchor.y() == Align::Max;
+        let layout = if from_bottom {
+            Layout::bottom_up(self.anchor.x())
+        } else {
+            Layout::top_down(self.anchor.x())
+        };
+        let margin = Vec2::splat(ctx.style().spacing.window_margin.sum().x / 2.);
+        let offset = margin * self.anchor.to_sign() * -1.;
+
+        // The newest toast is the closest to the corner
+        let newest_first: Vec<Toast> = queue.toasts.iter().rev().cloned().collect();
+        let area = Area::new(self.id)
+            .order(Order::Tooltip)
+            .anchor(self.anchor, offset)
+            .show(ctx, |ui| {
+                ui.with_layout(layout, |ui| {
+                    AnimatedList::new(self.id.with("list")).style(style).show(
+                        ui,
+                        newest_first,
+                        |ui, toast| {
+                            Frame::popup(ui.style()).show(ui, |ui| {
+                                ui.label(toast.text.clone());
+                            });
+                        },
+                    );
+                });
+            });
+
+        queue.hovered = area.response.contains_pointer();
+        if !queue.hovered {
+            let next_expiry = queue
+                .toasts
+                .iter()
+                .map(|toast| self.duration - toast.age.unwrap_or_default())
+                .reduce(f64::min);
+            if let Some(next_expiry) = next_expiry {
+                // `AnimatedList` repaints on its own while the toasts are moving
+                ctx.request_repaint_after_secs(next_expiry as f32);
+            }
+        }
+        ctx.data_mut(|data| data.insert_temp(self.id, queue));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{AreaState, Event, Pos2, RawInput, Rect, Shape};
+
+    fn toasts() -> Toasts {
+        Toasts::new("toasts").duration(1.)
+    }
+
+    /// Runs a frame at `time` showing only the toasts and returns the number of painted shapes.
+    ///
+    /// Like any egui area, the stack is invisible in the first frame it's shown.
+    fn run(ctx: &Context, time: f64, events: Vec<Event>) -> usize {
+        let input = RawInput {
+            time: Some(time),
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(800., 600.))),
+            events,
+            ..Default::default()
+        };
+        ctx.run(input, |ctx| toasts().show(ctx))
+            .shapes
+            .iter()
+            .filter(|clipped| !matches!(clipped.shape, Shape::Noop))
+            .count()
+    }
+
+    fn stack_center(ctx: &Context) -> Pos2 {
+        AreaState::load(ctx, toasts().id)
+            .expect("the toasts have been shown")
+            .rect()
+            .center()
+    }
+
+    #[test]
+    fn toast_appears() {
+        let ctx = Context::default();
+        assert_eq!(run(&ctx, 0., vec![]), 0);
+        toasts().add(&ctx, "Saved");
+        assert!(run(&ctx, 0.1, vec![]) > 0);
+    }
+
+    #[test]
+    fn toast_expires_after_duration() {
+        let ctx = Context::default();
+        run(&ctx, 0., vec![]);
+        toasts().add(&ctx, "Saved");
+        assert!(run(&ctx, 0.1, vec![]) > 0);
+        assert!(run(&ctx, 1., vec![]) > 0);
+        run(&ctx, 1.1, vec![]);
+        // Give the exit transition time to finish
+        assert_eq!(run(&ctx, 2., vec![]), 0);
+    }
+
+    #[test]
+    fn countdown_pauses_while_hovered() {
+        let ctx = Context::default();
+        run(&ctx, 0., vec![]);
+        toasts().add(&ctx, "Saved");
+        run(&ctx, 0.1, vec![]);
+        run(&ctx, 0.2, vec![Event::PointerMoved(stack_center(&ctx))]);
+        assert!(run(&ctx, 5., vec![]) > 0);
+        run(&ctx, 5.1, vec![Event::PointerMoved(Pos2::ZERO)]);
+        assert!(run(&ctx, 5.8, vec![]) > 0);
+        run(&ctx, 6.1, vec![]);
+        assert_eq!(run(&ctx, 7., vec![]), 0);
+    }
+
+    #[test]
+    fn time_before_adding_does_not_count() {
+        let ctx = Context::default();
+        run(&ctx, 0., vec![]);
+        toasts().add(&ctx, "Saved");
+        assert!(run(&ctx, 10., vec![]) > 0);
+        assert!(run(&ctx, 10.5, vec![]) > 0);
+    }
+}
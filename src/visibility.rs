@@ -0,0 +1,74 @@
+use egui::{Id, InnerResponse, Ui};
+
+use crate::{reveal::Reveal, TransitionStyle};
+
+/// Shows `add_contents` only when `visible`, with a transition when it appears or disappears.
+///
+/// The contents slide along the axis of [`TransitionStyle::t_type`] and fade.
+/// After `visible` becomes `false`, they are still shown (disabled) until the transition finishes.
+/// The space taken by the contents doesn't change during the transition,
+/// use [`animated_visibility_collapsing`] to animate it too.
+///
+/// Returns [`None`] if the contents are fully hidden.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::{animated_visibility, TransitionStyle};
+///
+/// let mut advanced = false; // Should be stored in your app's state
+///
+/// ui.checkbox(&mut advanced, "Show advanced settings");
+/// animated_visibility(ui, egui::Id::new("advanced"), advanced, &TransitionStyle::vertical(ui), |ui| {
+///     ui.label("Advanced settings");
+/// });
+/// # }
+/// ```
+pub fn animated_visibility<R>(
+    ui: &mut Ui,
+    id: Id,
+    visible: bool,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<InnerResponse<R>> {
+    visibility_impl(ui, id, visible, style, false, add_contents)
+}
+
+/// Same as [`animated_visibility`], but the space taken by the contents
+/// grows from and shrinks to zero along the main direction of the layout during the transition.
+pub fn animated_visibility_collapsing<R>(
+    ui: &mut Ui,
+    id: Id,
+    visible: bool,
+    style: &TransitionStyle,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<InnerResponse<R>> {
+    visibility_impl(ui, id, visible, style, true, add_contents)
+}
+
+fn visibility_impl<R>(
+    ui: &mut Ui,
+    id: Id,
+    visible: bool,
+    style: &TransitionStyle,
+    collapse: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<InnerResponse<R>> {
+    let visibility = ui.ctx().animate_bool_with_time_and_easing(
+        id.with("visibility"),
+        visible,
+        style.duration,
+        style.easing,
+    );
+    if !visible && visibility <= 0. {
+        return None;
+    }
+
+    Some(
+        Reveal::new(style, true, true, collapse).show(ui, id, visibility, |ui| {
+            if !visible {
+                ui.disable();
+            }
+            add_contents(ui)
+        }),
+    )
+}
@@ -0,0 +1,253 @@
+use egui::{Id, ProgressBar, RichText, Ui, WidgetText};
+use std::hash::Hash;
+
+use crate::{animated_pager_with_direction, PagerRet, TransitionStyle};
+
+type Validator<'a, State> = Box<dyn Fn(&State) -> Result<(), String> + 'a>;
+type SkipCondition<'a, State> = Box<dyn Fn(&State) -> bool + 'a>;
+
+struct WizardStep<'a, Step, State> {
+    step: Step,
+    title: WidgetText,
+    validate: Option<Validator<'a, State>>,
+    skip_if: Option<SkipCondition<'a, State>>,
+}
+
+/// Multi-step wizard (eg. onboarding or setup flow) with step headers, progress display and Next/Back buttons.
+///
+/// Going to the next step is animated _forward_, going back is animated _backward_.
+/// Each step can have a validation closure blocking the Next button and a condition for skipping it.
+/// Skipped steps are jumped over without animating through them.
+///
+/// The closures get the `state` passed to [`show`](Wizard::show), so they don't have to borrow your app's state.
+///
+/// ```
+/// # fn show(ui: &mut egui::Ui) {
+/// use egui_transition_animation::Wizard;
+///
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// enum Step {
+///     Name,
+///     Newsletter,
+///     Done,
+/// }
+///
+/// #[derive(Default)]
+/// struct Form {
+///     name: String,
+///     email: String,
+/// }
+///
+/// // Should be stored in your app's state
+/// let mut form = Form::default();
+/// let mut step = Step::Name;
+///
+/// let response = Wizard::new("setup")
+///     .step(Step::Name, "Name")
+///     .validate(|form: &Form| {
+///         if form.name.is_empty() {
+///             Err("Please enter your name".to_owned())
+///         } else {
+///             Ok(())
+///         }
+///     })
+///     .step(Step::Newsletter, "Newsletter")
+///     .skip_if(|form: &Form| form.name == "Anonymous")
+///     .step(Step::Done, "Done")
+///     .show(ui, &mut form, &mut step, |ui, form, step| match step {
+///         Step::Name => {
+///             ui.text_edit_singleline(&mut form.name);
+///         }
+///         Step::Newsletter => {
+///             ui.text_edit_singleline(&mut form.email);
+///         }
+///         Step::Done => {
+///             ui.label(format!("Welcome, {}!", form.name));
+///         }
+///     });
+///
+/// if response.finished {
+///     // Save the form
+/// }
+/// # }
+/// ```
+pub struct Wizard<'a, Step, State> {
+    id: Id,
+    style: Option<TransitionStyle>,
+    steps: Vec<WizardStep<'a, Step, State>>,
+}
+
+/// What happened in a [`Wizard`] this frame.
+pub struct WizardResponse<Step, Ret> {
+    /// Return value of the pager showing the steps
+    pub pager: PagerRet<Step, Ret>,
+    /// `true` if the Finish button on the last step was clicked and the step is valid
+    pub finished: bool,
+}
+
+impl<'a, Step: Sync + Send + Clone + 'static + Eq + Hash, State> Wizard<'a, Step, State> {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            style: None,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Style of the transition. Defaults to [`TransitionStyle::horizontal`].
+    pub fn style(mut self, style: TransitionStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Adds a step. Steps are shown in the order they are added.
+    pub fn step(mut self, step: Step, title: impl Into<WidgetText>) -> Self {
+        self.steps.push(WizardStep {
+            step,
+            title: title.into(),
+            validate: None,
+            skip_if: None,
+        });
+        self
+    }
+
+    /// Sets validation of the last added step.
+    ///
+    /// When it returns an error, going to the next step is blocked and the error is shown.
+    pub fn validate(mut self, validate: impl Fn(&State) -> Result<(), String> + 'a) -> Self {
+        if let Some(last) = self.steps.last_mut() {
+            last.validate = Some(Box::new(validate));
+        }
+        self
+    }
+
+    /// Sets the condition for skipping the last added step.
+    pub fn skip_if(mut self, skip_if: impl Fn(&State) -> bool + 'a) -> Self {
+        if let Some(last) = self.steps.last_mut() {
+            last.skip_if = Some(Box::new(skip_if));
+        }
+        self
+    }
+
+    /// Shows the wizard.
+    ///
+    /// # Parameters
+    ///  - `state`: State passed to `add_contents` and to the validation and skip closures.
+    ///  - `current`: The current step. Changed when the user navigates through the wizard.
+    ///  - `add_contents`: Function that shows given step in a [`Ui`].
+    ///    The step argument of this function may differ from `current` if an animation is running.
+    ///
+    /// # Panics
+    /// If no steps were added.
+    pub fn show<Ret>(
+        mut self,
+        ui: &mut Ui,
+        state: &mut State,
+        current: &mut Step,
+        add_contents: impl FnOnce(&mut Ui, &mut State, Step) -> Ret,
+    ) -> WizardResponse<Step, Ret> {
+        assert!(!self.steps.is_empty(), "Wizard needs at least one step");
+
+        let style = self
+            .style
+            .take()
+            .unwrap_or_else(|| TransitionStyle::horizontal(ui));
+        let error_id = self.id.with("error");
+
+        let skipped: Vec<bool> = self
+            .steps
+            .iter()
+            .map(|step| step.skip_if.as_ref().is_some_and(|skip_if| skip_if(state)))
+            .collect();
+        let position = |step: &Step| self.steps.iter().position(|other| other.step == *step);
+        let current_position = position(current).unwrap_or(0);
+        let active: Vec<usize> = (0..self.steps.len()).filter(|&i| !skipped[i]).collect();
+        let prev = active
+            .iter()
+            .rev()
+            .find(|&&i| i < current_position)
+            .copied();
+        let next = active.iter().find(|&&i| i > current_position).copied();
+
+        let mut go_to = None;
+        let mut finished = false;
+
+        ui.vertical(|ui| {
+            ui.horizontal_wrapped(|ui| {
+                for (i, step) in self.steps.iter().enumerate() {
+                    if i > 0 {
+                        ui.weak("›");
+                    }
+                    let title = step.title.clone();
+                    if i == current_position {
+                        ui.label(title.strong());
+                    } else if skipped[i] {
+                        ui.label(title.weak().strikethrough());
+                    } else if i < current_position {
+                        // Allow going back to any of the already completed steps
+                        if ui.link(title).clicked() {
+                            go_to = Some(i);
+                        }
+                    } else {
+                        ui.label(title.weak());
+                    }
+                }
+            });
+
+            let active_position = active
+                .iter()
+                .position(|&i| i == current_position)
+                .unwrap_or(0);
+            ui.add(
+                ProgressBar::new((active_position + 1) as f32 / active.len().max(1) as f32)
+                    .text(format!("Step {} of {}", active_position + 1, active.len())),
+            );
+            ui.separator();
+        });
+
+        let pager = animated_pager_with_direction(
+            ui,
+            current.clone(),
+            &style,
+            self.id.with("pager"),
+            |original_step, new_step| position(original_step) < position(new_step),
+            |ui, step| add_contents(ui, state, step),
+        );
+
+        let error: Option<String> = ui.ctx().data(|data| data.get_temp(error_id));
+        if let Some(error) = &error {
+            ui.label(RichText::new(error).color(ui.visuals().error_fg_color));
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(prev.is_some(), egui::Button::new("Back"))
+                .clicked()
+            {
+                go_to = prev;
+            }
+            let next_text = if next.is_some() { "Next" } else { "Finish" };
+            if ui.button(next_text).clicked() {
+                let validation = match &self.steps[current_position].validate {
+                    Some(validate) => validate(state),
+                    None => Ok(()),
+                };
+                match validation {
+                    Ok(()) => {
+                        go_to = next;
+                        finished = next.is_none();
+                        ui.ctx().data_mut(|data| data.remove::<String>(error_id));
+                    }
+                    Err(error) => ui.ctx().data_mut(|data| data.insert_temp(error_id, error)),
+                }
+            }
+        });
+
+        if let Some(go_to) = go_to {
+            *current = self.steps[go_to].step.clone();
+            ui.ctx().data_mut(|data| data.remove::<String>(error_id));
+        }
+
+        WizardResponse { pager, finished }
+    }
+}